@@ -1,7 +1,7 @@
 #![no_std]
 use soroban_sdk::{
-    Address, Env, Symbol, Vec, contract, contracterror, contractimpl, contracttype, symbol_short,
-    token,
+    Address, Bytes, BytesN, Env, IntoVal, Symbol, Val, Vec, contract, contractclient,
+    contracterror, contractimpl, contracttype, symbol_short, token, xdr::ToXdr,
 };
 
 // Milestone status tracking
@@ -11,6 +11,8 @@ pub enum MilestoneStatus {
     Pending,
     Released,
     Disputed,
+    Cancelled,
+    Refunded,
 }
 
 // Individual milestone in an escrow
@@ -20,6 +22,10 @@ pub struct Milestone {
     pub amount: i128,
     pub status: MilestoneStatus,
     pub description: Symbol,
+    // Timestamp after which the milestone can no longer be released; 0 means no deadline
+    pub deadline: u64,
+    // Amount already paid out via partial releases; equals `amount` once released
+    pub released_so_far: i128,
 }
 
 // Overall escrow status
@@ -35,6 +41,7 @@ pub enum EscrowStatus {
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct Escrow {
+    pub creator: Address,
     pub depositor: Address,
     pub recipient: Address,
     pub total_amount: i128,
@@ -42,6 +49,56 @@ pub struct Escrow {
     pub milestones: Vec<Milestone>,
     pub token: Address,
     pub status: EscrowStatus,
+    pub payout_token: Option<Address>,
+    pub swap_contract: Option<Address>,
+    pub updated_at: u64,
+    pub release_operators: Vec<Address>,
+    pub release_blocked: bool,
+    pub arbiter: Option<Address>,
+    pub auto_complete: bool,
+}
+
+// Reason a milestone cannot currently be released
+#[contracttype]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReleaseBlocker {
+    None,
+    EscrowNotActive,
+    AlreadyReleased,
+    MilestoneCancelled,
+    DisputePending,
+    ReleaseBlocked,
+    MilestoneRefunded,
+    DeadlinePassed,
+    ContractPaused,
+}
+
+// Whether a milestone can currently be released, and why not
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReleaseEligibility {
+    pub eligible: bool,
+    pub reason: ReleaseBlocker,
+}
+
+// Inputs for one escrow in a bulk `create_escrows` call
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct CreateEscrowArgs {
+    pub depositor: Address,
+    pub recipient: Address,
+    pub milestones: Vec<Milestone>,
+    pub token: Address,
+}
+
+// Contract-wide configuration snapshot for integrators
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EscrowMetadata {
+    pub admin: Option<Address>,
+    pub fee_bps: i128,
+    pub max_milestones: u32,
+    pub paused: bool,
 }
 
 // Contract error types
@@ -63,13 +120,76 @@ pub enum Error {
     ZeroAmount = 13,
     InvalidDeadline = 14,
     SelfDealing = 15,
+    SwapFailed = 16,
+    InvalidRecipient = 17,
+    MilestoneNotPending = 18,
+    DuplicateMilestone = 19,
+    BalanceMismatch = 20,
+    InvalidStatusTransition = 21,
+    SignerNotSet = 22,
+    AmountTooLarge = 23,
+    InvalidToken = 24,
+    ReleaseBlocked = 25,
+    ProposalExists = 26,
+    ProposalNotFound = 27,
+    ProposalNotReady = 28,
+    ContractSunset = 29,
+    MilestoneDisputed = 30,
+    ArbiterNotSet = 31,
+    ContractPaused = 32,
+    PartialAmountExceeded = 33,
+    DeadlinePassed = 34,
+    InvalidTtlConfig = 35,
+    BelowMinimum = 36,
+    TotalOverflow = 37,
+    AlreadyInitialized = 38,
+    ArbiterAlreadySet = 39,
+}
+
+// Interface a swap contract must expose to convert milestone payouts.
+// The escrow transfers `amount_in` of `token_in` to the swap contract before
+// calling `swap`, which must send the converted `token_out` amount to `to`
+// and return it. The release reverts unless `to` received at least that much.
+#[contractclient(name = "SwapClient")]
+pub trait SwapInterface {
+    fn swap(env: Env, token_in: Address, token_out: Address, amount_in: i128, to: Address) -> i128;
 }
 
+// Interface version reported by `version`; bump on breaking changes
+const CONTRACT_VERSION: u32 = 1;
+
 // Platform fee configuration (in basis points: 1 bps = 0.01%)
 // Default: 50 bps = 0.5%
 const DEFAULT_FEE_BPS: i128 = 50;
 const BPS_DENOMINATOR: i128 = 10000;
 
+// Default maximum number of milestones per escrow
+const DEFAULT_MAX_MILESTONES: u32 = 20;
+
+// Default delay between proposing and executing a release: 1 day
+const DEFAULT_RELEASE_DELAY: u64 = 86_400;
+
+// Maximum number of release operators per escrow
+const MAX_RELEASE_OPERATORS: u32 = 10;
+
+// Maximum number of escrows handled by a single batch call
+const MAX_BATCH_SIZE: u32 = 50;
+
+// Maximum number of escrow ids kept in a per-address index
+const MAX_INDEX_SIZE: u32 = 100;
+
+// Cancellation fee is capped at 1000 bps = 10% of the refund
+const MAX_CANCEL_FEE_BPS: i128 = 1000;
+
+// XDR encoding of the Stellar `Asset::Native` variant (XLM)
+const NATIVE_ASSET_XDR: [u8; 4] = [0, 0, 0, 0];
+
+// Persistent storage TTL bump configuration (in ledgers)
+// Default TTL policy: an escrow entry is extended to ESCROW_TTL_EXTEND_TO once
+// its remaining TTL drops below ESCROW_TTL_THRESHOLD.
+const ESCROW_TTL_THRESHOLD: u32 = 100;
+const ESCROW_TTL_EXTEND_TO: u32 = 2_000_000;
+
 #[contract]
 pub struct VaultixEscrow;
 
@@ -77,13 +197,21 @@ pub struct VaultixEscrow;
 impl VaultixEscrow {
     /// Initializes the contract with treasury address and optional fee configuration.
     ///
+    /// Can only be called once. The treasury also becomes the contract admin,
+    /// stored separately so the role can later be handed over via `set_admin`.
+    ///
     /// # Arguments
     /// * `treasury` - Address that will receive platform fees
     /// * `fee_bps` - Optional fee in basis points (default: 50 bps = 0.5%)
     ///
     /// # Errors
+    /// * `AlreadyInitialized` - If the contract has already been initialized
     /// * `InvalidFeeConfiguration` - If fee_bps exceeds 10000 (100%)
     pub fn initialize(env: Env, treasury: Address, fee_bps: Option<i128>) -> Result<(), Error> {
+        if env.storage().instance().has(&symbol_short!("admin")) {
+            return Err(Error::AlreadyInitialized);
+        }
+
         // Verify treasury address authorization
         treasury.require_auth();
 
@@ -94,10 +222,13 @@ impl VaultixEscrow {
             return Err(Error::InvalidFeeConfiguration);
         }
 
-        // Store treasury address
+        // Store treasury address, which starts out as the admin too
         env.storage()
             .instance()
             .set(&symbol_short!("treasury"), &treasury);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("admin"), &treasury);
 
         // Store fee configuration
         env.storage()
@@ -114,16 +245,10 @@ impl VaultixEscrow {
     ///
     /// # Errors
     /// * `TreasuryNotInitialized` - If contract not initialized
-    /// * `UnauthorizedAccess` - If caller is not treasury
+    /// * `UnauthorizedAccess` - If caller is not the admin
     /// * `InvalidFeeConfiguration` - If fee exceeds 100%
     pub fn update_fee(env: Env, new_fee_bps: i128) -> Result<(), Error> {
-        let treasury: Address = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("treasury"))
-            .ok_or(Error::TreasuryNotInitialized)?;
-
-        treasury.require_auth();
+        require_admin(&env)?;
 
         if !(0..=BPS_DENOMINATOR).contains(&new_fee_bps) {
             return Err(Error::InvalidFeeConfiguration);
@@ -136,241 +261,1603 @@ impl VaultixEscrow {
         Ok(())
     }
 
-    /// Returns the current treasury address and fee configuration.
-    pub fn get_config(env: Env) -> Result<(Address, i128), Error> {
-        let treasury: Address = env
-            .storage()
+    /// Updates the fee charged on escrow cancellation (admin only).
+    ///
+    /// The fee is deducted from the depositor's refund and sent to the treasury.
+    ///
+    /// # Arguments
+    /// * `cancel_fee_bps` - Cancellation fee in basis points (max 1000 = 10%)
+    ///
+    /// # Errors
+    /// * `TreasuryNotInitialized` - If contract not initialized
+    /// * `UnauthorizedAccess` - If caller is not the admin
+    /// * `InvalidFeeConfiguration` - If fee exceeds the 10% cap
+    pub fn update_cancel_fee(env: Env, cancel_fee_bps: i128) -> Result<(), Error> {
+        require_admin(&env)?;
+
+        if !(0..=MAX_CANCEL_FEE_BPS).contains(&cancel_fee_bps) {
+            return Err(Error::InvalidFeeConfiguration);
+        }
+
+        env.storage()
             .instance()
-            .get(&symbol_short!("treasury"))
-            .ok_or(Error::TreasuryNotInitialized)?;
+            .set(&symbol_short!("cncl_fee"), &cancel_fee_bps);
 
-        let fee_bps: i128 = env
-            .storage()
+        Ok(())
+    }
+
+    /// Returns the current cancellation fee in basis points (default: 0).
+    pub fn get_cancel_fee(env: Env) -> i128 {
+        env.storage()
             .instance()
-            .get(&symbol_short!("fee_bps"))
-            .unwrap_or(DEFAULT_FEE_BPS);
+            .get(&symbol_short!("cncl_fee"))
+            .unwrap_or(0)
+    }
 
-        Ok((treasury, fee_bps))
+    /// Enables or disables strict milestone validation (admin only).
+    ///
+    /// When enabled, `create_escrow` rejects escrows whose milestones share a
+    /// description. Disabled by default for compatibility.
+    ///
+    /// # Errors
+    /// * `TreasuryNotInitialized` - If contract not initialized
+    /// * `UnauthorizedAccess` - If caller is not the admin
+    pub fn set_strict_milestones(env: Env, enabled: bool) -> Result<(), Error> {
+        require_admin(&env)?;
+
+        env.storage()
+            .instance()
+            .set(&symbol_short!("strict"), &enabled);
+
+        Ok(())
     }
 
-    /// Creates a new escrow with milestone-based payment releases.
+    /// Sets an absolute ceiling on any single escrow's total amount (admin only).
     ///
-    /// # Arguments
-    /// * `escrow_id` - Unique identifier for the escrow
-    /// * `depositor` - Address funding the escrow
-    /// * `recipient` - Address receiving milestone payments
-    /// * `milestones` - Vector of milestones defining payment schedule
-    /// * `token` - Token contract address for payments
+    /// Guards against absurd escrows that could never realistically be funded.
+    /// No ceiling applies until one is set.
     ///
     /// # Errors
-    /// * `EscrowAlreadyExists` - If escrow_id is already in use
-    /// * `VectorTooLarge` - If more than 20 milestones provided
-    /// * `InvalidMilestoneAmount` - If any milestone amount is zero or negative
-    pub fn create_escrow(
-        env: Env,
-        escrow_id: u64,
-        depositor: Address,
-        recipient: Address,
-        milestones: Vec<Milestone>,
-        token: Address,
-    ) -> Result<(), Error> {
-        // Authenticate the depositor
-        depositor.require_auth();
+    /// * `TreasuryNotInitialized` - If contract not initialized
+    /// * `UnauthorizedAccess` - If caller is not the admin
+    /// * `InvalidMilestoneAmount` - If the ceiling is zero or negative
+    pub fn set_max_total_amount(env: Env, max_total_amount: i128) -> Result<(), Error> {
+        require_admin(&env)?;
 
-        // Validate no self-dealing (depositor cannot be recipient)
-        if depositor == recipient {
-            return Err(Error::SelfDealing);
+        if max_total_amount <= 0 {
+            return Err(Error::InvalidMilestoneAmount);
         }
 
-        // Check if escrow already exists
-        let storage_key = get_storage_key(escrow_id);
-        if env.storage().persistent().has(&storage_key) {
-            return Err(Error::EscrowAlreadyExists);
-        }
+        env.storage()
+            .instance()
+            .set(&symbol_short!("max_total"), &max_total_amount);
 
-        // Validate milestones and calculate total
-        let total_amount = validate_milestones(&milestones)?;
+        Ok(())
+    }
 
-        // Initialize all milestones to Pending status
-        let mut initialized_milestones = Vec::new(&env);
-        for milestone in milestones.iter() {
-            let mut m = milestone.clone();
-            m.status = MilestoneStatus::Pending;
-            initialized_milestones.push_back(m);
-        }
-
-        // Create the escrow
-        let escrow = Escrow {
-            depositor: depositor.clone(),
-            recipient,
-            total_amount,
-            total_released: 0,
-            milestones: initialized_milestones,
-            token: token.clone(),
-            status: EscrowStatus::Active,
-        };
+    /// Sets a floor on any single escrow's total amount (admin only).
+    ///
+    /// Keeps out dust escrows that waste gas or fall below a token's transfer
+    /// threshold. Defaults to 1, i.e. no effective minimum.
+    ///
+    /// # Errors
+    /// * `TreasuryNotInitialized` - If contract not initialized
+    /// * `UnauthorizedAccess` - If caller is not the admin
+    /// * `InvalidMilestoneAmount` - If the minimum is zero or negative
+    pub fn set_min_total_amount(env: Env, min_total_amount: i128) -> Result<(), Error> {
+        require_admin(&env)?;
 
-        // Save to persistent storage
-        env.storage().persistent().set(&storage_key, &escrow);
+        if min_total_amount <= 0 {
+            return Err(Error::InvalidMilestoneAmount);
+        }
 
-        // Transfer funds from depositor to contract
-        let token_client = token::Client::new(&env, &token);
-        token_client.transfer(&depositor, env.current_contract_address(), &total_amount);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("min_total"), &min_total_amount);
 
         Ok(())
     }
 
-    /// Retrieves escrow details (read-only)
-    pub fn get_escrow(env: Env, escrow_id: u64) -> Result<Escrow, Error> {
-        let storage_key = get_storage_key(escrow_id);
+    /// Returns the minimum total amount a new escrow must hold.
+    pub fn get_min_total_amount(env: Env) -> i128 {
         env.storage()
-            .persistent()
-            .get(&storage_key)
-            .ok_or(Error::EscrowNotFound)
+            .instance()
+            .get(&symbol_short!("min_total"))
+            .unwrap_or(1)
     }
 
-    /// Read-only helper to fetch escrow status
-    pub fn get_state(env: Env, escrow_id: u64) -> Result<EscrowStatus, Error> {
-        let escrow = Self::get_escrow(env, escrow_id)?;
-        Ok(escrow.status)
+    /// Sets the minimum size of each milestone, in whole token units (admin only).
+    ///
+    /// The minimum scales with each token's decimals, so `1` means one full
+    /// token (e.g. 10^7 base units for a 7-decimal token). Defaults to 0, i.e.
+    /// no minimum.
+    ///
+    /// # Errors
+    /// * `TreasuryNotInitialized` - If contract not initialized
+    /// * `UnauthorizedAccess` - If caller is not the admin
+    pub fn set_min_milestone_units(env: Env, min_units: u32) -> Result<(), Error> {
+        require_admin(&env)?;
+
+        env.storage()
+            .instance()
+            .set(&symbol_short!("min_units"), &min_units);
+
+        Ok(())
     }
 
-    /// Releases a specific milestone payment to the recipient with platform fee deduction.
+    /// Returns the minimum size of each milestone, in whole token units.
+    pub fn get_min_milestone_units(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("min_units"))
+            .unwrap_or(0)
+    }
+
+    /// Sets the maximum number of milestones a new escrow may have (admin only).
     ///
     /// # Arguments
-    /// * `escrow_id` - Identifier of the escrow
-    /// * `milestone_index` - Index of the milestone to release
-    /// * `token_address` - Address of the token contract for transfers
+    /// * `max_milestones` - Milestone cap (default: 20)
     ///
     /// # Errors
-    /// * `EscrowNotFound` - If escrow doesn't exist
-    /// * `UnauthorizedAccess` - If caller is not the depositor
-    /// * `EscrowNotActive` - If escrow is completed or cancelled
-    /// * `MilestoneNotFound` - If index is out of bounds
-    /// * `MilestoneAlreadyReleased` - If milestone was already released
     /// * `TreasuryNotInitialized` - If contract not initialized
-    ///
-    /// # Fee Calculation
-    /// Platform fee is calculated using basis points: fee = (amount * fee_bps) / 10000
-    /// The recipient receives: amount - fee
-    /// The treasury receives: fee
-    pub fn release_milestone(
-        env: Env,
-        escrow_id: u64,
-        milestone_index: u32,
-        token_address: Address,
-    ) -> Result<(), Error> {
-        let storage_key = get_storage_key(escrow_id);
+    /// * `UnauthorizedAccess` - If caller is not the admin
+    pub fn set_max_milestones(env: Env, max_milestones: u32) -> Result<(), Error> {
+        require_admin(&env)?;
 
-        // Load escrow from storage
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&storage_key)
-            .ok_or(Error::EscrowNotFound)?;
-
-        // Verify authorization
-        escrow.depositor.require_auth();
+        env.storage()
+            .instance()
+            .set(&symbol_short!("max_ms"), &max_milestones);
 
-        // Check escrow is active
-        if escrow.status != EscrowStatus::Active {
-            return Err(Error::EscrowNotActive);
-        }
+        Ok(())
+    }
 
-        // Verify milestone index is valid
-        if milestone_index >= escrow.milestones.len() {
-            return Err(Error::MilestoneNotFound);
-        }
+    /// Returns the maximum number of milestones a new escrow may have.
+    pub fn get_max_milestones(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("max_ms"))
+            .unwrap_or(DEFAULT_MAX_MILESTONES)
+    }
 
-        // Get the milestone
-        let mut milestone = escrow
-            .milestones
-            .get(milestone_index)
-            .ok_or(Error::MilestoneNotFound)?;
+    /// Sets the TTL policy used by `extend_escrow_ttl` (admin only).
+    ///
+    /// # Arguments
+    /// * `threshold` - Remaining TTL (in ledgers) below which an escrow is extended (default: 100)
+    /// * `extend_to` - TTL (in ledgers) the escrow is extended to (default: 2000000)
+    ///
+    /// # Errors
+    /// * `TreasuryNotInitialized` - If contract not initialized
+    /// * `UnauthorizedAccess` - If caller is not the admin
+    /// * `InvalidTtlConfig` - If `extend_to` is zero or below `threshold`
+    pub fn set_ttl_config(env: Env, threshold: u32, extend_to: u32) -> Result<(), Error> {
+        require_admin(&env)?;
 
-        // Check if already released
-        if milestone.status == MilestoneStatus::Released {
-            return Err(Error::MilestoneAlreadyReleased);
+        if extend_to == 0 || extend_to < threshold {
+            return Err(Error::InvalidTtlConfig);
         }
 
-        // Get treasury and fee configuration
-        let (treasury, fee_bps) = Self::get_config(env.clone())?;
-
-        // Calculate platform fee using integer math
-        // fee = (amount * fee_bps) / 10000
-        let fee = calculate_fee(milestone.amount, fee_bps)?;
-        let payout = milestone
-            .amount
-            .checked_sub(fee)
-            .ok_or(Error::InvalidMilestoneAmount)?;
+        env.storage()
+            .instance()
+            .set(&symbol_short!("ttl_cfg"), &(threshold, extend_to));
 
-        // Create token client for transfers
-        let token_client = token::TokenClient::new(&env, &token_address);
+        Ok(())
+    }
 
-        // Transfer payout to recipient (seller)
-        token_client.transfer(&env.current_contract_address(), &escrow.recipient, &payout);
+    /// Returns the TTL policy as `(threshold, extend_to)`, in ledgers.
+    pub fn get_ttl_config(env: Env) -> (u32, u32) {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("ttl_cfg"))
+            .unwrap_or((ESCROW_TTL_THRESHOLD, ESCROW_TTL_EXTEND_TO))
+    }
 
-        // Transfer fee to treasury (only if fee > 0)
-        if fee > 0 {
-            token_client.transfer(&env.current_contract_address(), &treasury, &fee);
+    /// Sets the delay between proposing and executing a release (admin only).
+    ///
+    /// # Arguments
+    /// * `delay` - Delay in seconds (default: 86400 = 1 day)
+    ///
+    /// # Errors
+    /// * `TreasuryNotInitialized` - If contract not initialized
+    /// * `UnauthorizedAccess` - If caller is not the admin
+    pub fn set_release_delay(env: Env, delay: u64) -> Result<(), Error> {
+        require_admin(&env)?;
 
-            // Emit event for fee collection
-            #[allow(deprecated)]
-            env.events().publish(
-                (symbol_short!("fee_coll"), escrow_id, milestone_index),
-                (fee, treasury.clone()),
-            );
-        }
+        env.storage()
+            .instance()
+            .set(&symbol_short!("rel_delay"), &delay);
 
-        // Update milestone status
-        milestone.status = MilestoneStatus::Released;
-        escrow.milestones.set(milestone_index, milestone.clone());
+        Ok(())
+    }
 
-        // Update total released with overflow protection
-        escrow.total_released = escrow
-            .total_released
-            .checked_add(milestone.amount)
-            .ok_or(Error::InvalidMilestoneAmount)?;
+    /// Permanently disables creation of new escrows (admin only).
+    ///
+    /// Existing escrows keep working: release, cancel and refund paths are
+    /// unaffected so they can wind down. This cannot be undone.
+    ///
+    /// # Errors
+    /// * `TreasuryNotInitialized` - If contract not initialized
+    /// * `UnauthorizedAccess` - If caller is not the admin
+    pub fn sunset(env: Env) -> Result<(), Error> {
+        let admin = require_admin(&env)?;
 
-        // Save updated escrow
-        env.storage().persistent().set(&storage_key, &escrow);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("sunset"), &true);
 
-        // Emit event for milestone release
         #[allow(deprecated)]
-        env.events().publish(
-            (symbol_short!("released"), escrow_id, milestone_index),
-            (payout, escrow.recipient.clone()),
-        );
+        env.events().publish((symbol_short!("sunset"),), admin);
 
         Ok(())
     }
 
-    /// Buyer confirms delivery and releases a milestone to the recipient.
+    /// Halts all fund-moving operations in an emergency (admin only).
     ///
-    /// # Arguments
-    /// * `escrow_id` - Identifier of the escrow
-    /// * `milestone_index` - Index of the milestone to release
-    /// * `buyer` - Buyer address confirming the delivery
+    /// While paused, creating, releasing, cancelling and refunding escrows
+    /// fail with `ContractPaused`; read-only queries keep working.
     ///
     /// # Errors
-    /// * `EscrowNotFound` - If escrow doesn't exist
-    /// * `UnauthorizedAccess` - If caller is not the buyer/depositor
-    /// * `EscrowNotActive` - If escrow is completed or cancelled
-    /// * `MilestoneNotFound` - If index is out of bounds
-    /// * `MilestoneAlreadyReleased` - If milestone was already released
-    pub fn confirm_delivery(
-        env: Env,
-        escrow_id: u64,
-        milestone_index: u32,
-        buyer: Address,
-    ) -> Result<(), Error> {
-        let storage_key = get_storage_key(escrow_id);
+    /// * `TreasuryNotInitialized` - If contract not initialized
+    /// * `UnauthorizedAccess` - If caller is not the admin
+    pub fn pause(env: Env) -> Result<(), Error> {
+        let admin = require_admin(&env)?;
 
-        // Load escrow from storage
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&storage_key)
+        env.storage()
+            .instance()
+            .set(&symbol_short!("paused"), &true);
+
+        #[allow(deprecated)]
+        env.events().publish((symbol_short!("paused"),), admin);
+
+        Ok(())
+    }
+
+    /// Resumes fund-moving operations after a pause (admin only).
+    ///
+    /// # Errors
+    /// * `TreasuryNotInitialized` - If contract not initialized
+    /// * `UnauthorizedAccess` - If caller is not the admin
+    pub fn unpause(env: Env) -> Result<(), Error> {
+        let admin = require_admin(&env)?;
+
+        env.storage()
+            .instance()
+            .set(&symbol_short!("paused"), &false);
+
+        #[allow(deprecated)]
+        env.events().publish((symbol_short!("unpaused"),), admin);
+
+        Ok(())
+    }
+
+    /// Returns whether fund-moving operations are paused.
+    pub fn is_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("paused"))
+            .unwrap_or(false)
+    }
+
+    /// Returns whether the contract has been sunset.
+    pub fn is_sunset(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("sunset"))
+            .unwrap_or(false)
+    }
+
+    /// Returns the delay in seconds between proposing and executing a release.
+    pub fn get_release_delay(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("rel_delay"))
+            .unwrap_or(DEFAULT_RELEASE_DELAY)
+    }
+
+    /// Returns the total platform and cancellation fees paid to a fee recipient.
+    pub fn get_fees_collected(env: Env, fee_recipient: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("fees"), fee_recipient))
+            .unwrap_or(0)
+    }
+
+    /// Returns the current treasury address and fee configuration.
+    pub fn get_config(env: Env) -> Result<(Address, i128), Error> {
+        let treasury: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("treasury"))
+            .ok_or(Error::TreasuryNotInitialized)?;
+
+        let fee_bps: i128 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("fee_bps"))
+            .unwrap_or(DEFAULT_FEE_BPS);
+
+        Ok((treasury, fee_bps))
+    }
+
+    /// Hands the admin role to a new address (admin only).
+    ///
+    /// The treasury that receives fees is unaffected.
+    ///
+    /// # Errors
+    /// * `TreasuryNotInitialized` - If contract not initialized
+    /// * `UnauthorizedAccess` - If caller is not the admin
+    pub fn set_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        let admin = require_admin(&env)?;
+
+        env.storage()
+            .instance()
+            .set(&symbol_short!("admin"), &new_admin);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((symbol_short!("admin"),), (admin, new_admin));
+
+        Ok(())
+    }
+
+    /// Returns the contract admin.
+    ///
+    /// # Errors
+    /// * `TreasuryNotInitialized` - If contract not initialized
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("admin"))
+            .ok_or(Error::TreasuryNotInitialized)
+    }
+
+    /// Returns the contract interface version.
+    pub fn version(_env: Env) -> u32 {
+        CONTRACT_VERSION
+    }
+
+    /// Returns the contract-wide configuration in a single call.
+    ///
+    /// `admin` is `None` until the contract has been initialized.
+    pub fn metadata(env: Env) -> EscrowMetadata {
+        EscrowMetadata {
+            admin: env.storage().instance().get(&symbol_short!("admin")),
+            fee_bps: env
+                .storage()
+                .instance()
+                .get(&symbol_short!("fee_bps"))
+                .unwrap_or(DEFAULT_FEE_BPS),
+            max_milestones: Self::get_max_milestones(env.clone()),
+            paused: Self::is_paused(env),
+        }
+    }
+
+    /// Creates a new escrow with milestone-based payment releases.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Unique identifier for the escrow
+    /// * `depositor` - Address funding the escrow
+    /// * `recipient` - Address receiving milestone payments
+    /// * `milestones` - Vector of milestones defining payment schedule
+    /// * `token` - Token contract address for payments
+    ///
+    /// # Errors
+    /// * `EscrowAlreadyExists` - If escrow_id is already in use
+    /// * `VectorTooLarge` - If more milestones than the configured cap (default 20) provided
+    /// * `InvalidMilestoneAmount` - If any milestone amount is zero or negative
+    /// * `InvalidDeadline` - If any milestone deadline is already in the past
+    /// * `DuplicateMilestone` - If strict mode is on and two milestones share a description
+    /// * `TotalOverflow` - If the milestone amounts sum past the representable maximum
+    /// * `AmountTooLarge` - If the total exceeds the configured ceiling
+    /// * `BelowMinimum` - If the total or any milestone is below the configured minimum
+    /// * `SelfDealing` - If depositor and recipient are the same address
+    /// * `InvalidRecipient` - If recipient is the token or this escrow contract
+    /// * `InvalidToken` - If `token` is not a token contract
+    /// * `ContractSunset` - If the contract no longer accepts new escrows
+    /// * `ContractPaused` - If fund-moving operations are paused
+    pub fn create_escrow(
+        env: Env,
+        escrow_id: u64,
+        depositor: Address,
+        recipient: Address,
+        milestones: Vec<Milestone>,
+        token: Address,
+    ) -> Result<(), Error> {
+        // Authenticate the depositor
+        depositor.require_auth();
+
+        open_escrow(&env, escrow_id, depositor, recipient, milestones, token)
+    }
+
+    /// Creates a new escrow funded in native XLM.
+    ///
+    /// Resolves the address of the native asset's Stellar Asset Contract (SAC)
+    /// and uses it as the escrow token, so callers don't have to look it up.
+    /// The native SAC is deployed on all public networks; on a network where it
+    /// has not been deployed the funding transfer fails and creation reverts.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Unique identifier for the escrow
+    /// * `depositor` - Address funding the escrow
+    /// * `recipient` - Address receiving milestone payments
+    /// * `milestones` - Vector of milestones defining payment schedule
+    ///
+    /// # Errors
+    /// Same as `create_escrow`.
+    pub fn create_xlm_escrow(
+        env: Env,
+        escrow_id: u64,
+        depositor: Address,
+        recipient: Address,
+        milestones: Vec<Milestone>,
+    ) -> Result<(), Error> {
+        let token = native_token_address(&env);
+        Self::create_escrow(env, escrow_id, depositor, recipient, milestones, token)
+    }
+
+    /// Creates a new escrow under the next free auto-generated id.
+    ///
+    /// Ids are assigned from a counter starting at 0, skipping any id already
+    /// taken by an escrow created with an explicit id.
+    ///
+    /// # Arguments
+    /// * `depositor` - Address funding the escrow
+    /// * `recipient` - Address receiving milestone payments
+    /// * `milestones` - Vector of milestones defining payment schedule
+    /// * `token` - Token contract address for payments
+    ///
+    /// # Returns
+    /// The id assigned to the new escrow
+    ///
+    /// # Errors
+    /// Same as `create_escrow`.
+    pub fn create_escrow_auto(
+        env: Env,
+        depositor: Address,
+        recipient: Address,
+        milestones: Vec<Milestone>,
+        token: Address,
+    ) -> Result<u64, Error> {
+        // Authenticate the depositor
+        depositor.require_auth();
+
+        open_escrow_auto(&env, depositor, recipient, milestones, token)
+    }
+
+    /// Creates several escrows in one call under auto-generated ids.
+    ///
+    /// Either every escrow is created or, if any entry fails validation or
+    /// funding, the whole call reverts.
+    ///
+    /// # Arguments
+    /// * `escrows` - Inputs for each escrow, at most 50
+    ///
+    /// # Returns
+    /// The ids assigned to the new escrows, in input order
+    ///
+    /// # Errors
+    /// * `VectorTooLarge` - If more than 50 escrows are given
+    /// * Any error returned by `create_escrow` for one of the entries
+    pub fn create_escrows(env: Env, escrows: Vec<CreateEscrowArgs>) -> Result<Vec<u64>, Error> {
+        if escrows.len() > MAX_BATCH_SIZE {
+            return Err(Error::VectorTooLarge);
+        }
+
+        // Authenticate each depositor once, however many escrows they fund
+        let mut depositors: Vec<Address> = Vec::new(&env);
+        for args in escrows.iter() {
+            if !depositors.contains(&args.depositor) {
+                args.depositor.require_auth();
+                depositors.push_back(args.depositor);
+            }
+        }
+
+        let mut escrow_ids = Vec::new(&env);
+        for args in escrows.iter() {
+            let escrow_id = open_escrow_auto(
+                &env,
+                args.depositor,
+                args.recipient,
+                args.milestones,
+                args.token,
+            )?;
+            escrow_ids.push_back(escrow_id);
+        }
+        Ok(escrow_ids)
+    }
+
+    /// Retrieves escrow details (read-only)
+    pub fn get_escrow(env: Env, escrow_id: u64) -> Result<Escrow, Error> {
+        let storage_key = get_storage_key(escrow_id);
+        env.storage()
+            .persistent()
+            .get(&storage_key)
+            .ok_or(Error::EscrowNotFound)
+    }
+
+    /// Retrieves several escrows at once, in the order requested (read-only).
+    ///
+    /// Ids without an escrow yield `None`.
+    ///
+    /// # Errors
+    /// * `VectorTooLarge` - If more than 50 ids are requested
+    pub fn get_escrows(env: Env, escrow_ids: Vec<u64>) -> Result<Vec<Option<Escrow>>, Error> {
+        if escrow_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::VectorTooLarge);
+        }
+
+        let mut escrows = Vec::new(&env);
+        for escrow_id in escrow_ids.iter() {
+            escrows.push_back(env.storage().persistent().get(&get_storage_key(escrow_id)));
+        }
+        Ok(escrows)
+    }
+
+    /// Extends the persistent TTL of an escrow so it does not expire while dormant.
+    ///
+    /// Callable by anyone; the TTL is bumped using the contract's configured values.
+    /// Entries the escrow depends on are bumped too: its tracked balance, the
+    /// locked totals and address indexes it appears in, and any release
+    /// signer, nonce, pending proposals and dispute history.
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    pub fn extend_escrow_ttl(env: Env, escrow_id: u64) -> Result<(), Error> {
+        let escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        let ttl = Self::get_ttl_config(env.clone());
+        extend_if_present(&env, &get_storage_key(escrow_id), ttl);
+        extend_if_present(&env, &get_balance_key(escrow_id), ttl);
+        extend_if_present(&env, &(symbol_short!("locked"), escrow.token.clone()), ttl);
+        extend_if_present(
+            &env,
+            &get_depositor_locked_key(&escrow.depositor, &escrow.token),
+            ttl,
+        );
+        extend_if_present(&env, &(symbol_short!("dep_idx"), escrow.depositor), ttl);
+        extend_if_present(&env, &(symbol_short!("rcp_idx"), escrow.recipient), ttl);
+        extend_if_present(&env, &(symbol_short!("signer"), escrow_id), ttl);
+        extend_if_present(&env, &(symbol_short!("nonce"), escrow_id), ttl);
+        for index in 0..escrow.milestones.len() {
+            extend_if_present(&env, &get_proposal_key(escrow_id, index), ttl);
+            extend_if_present(&env, &get_dispute_history_key(escrow_id, index), ttl);
+        }
+
+        Ok(())
+    }
+
+    /// Read-only helper to fetch escrow status
+    pub fn get_state(env: Env, escrow_id: u64) -> Result<EscrowStatus, Error> {
+        let escrow = Self::get_escrow(env, escrow_id)?;
+        Ok(escrow.status)
+    }
+
+    /// Read-only helper reporting whether every milestone is released or cancelled
+    pub fn all_released(env: Env, escrow_id: u64) -> Result<bool, Error> {
+        let escrow = Self::get_escrow(env, escrow_id)?;
+        Ok(verify_all_released(&escrow.milestones))
+    }
+
+    /// Read-only helper listing the indices of released milestones in order
+    pub fn get_released_indices(env: Env, escrow_id: u64) -> Result<Vec<u32>, Error> {
+        let escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        let mut indices = Vec::new(&env);
+        for (index, milestone) in escrow.milestones.iter().enumerate() {
+            if milestone.status == MilestoneStatus::Released {
+                indices.push_back(index as u32);
+            }
+        }
+        Ok(indices)
+    }
+
+    /// Read-only helper listing `(index, milestone)` pairs whose status matches
+    pub fn get_milestones_by_status(
+        env: Env,
+        escrow_id: u64,
+        status: MilestoneStatus,
+    ) -> Result<Vec<(u32, Milestone)>, Error> {
+        let escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        let mut matching = Vec::new(&env);
+        for (index, milestone) in escrow.milestones.iter().enumerate() {
+            if milestone.status == status {
+                matching.push_back((index as u32, milestone));
+            }
+        }
+        Ok(matching)
+    }
+
+    /// Read-only helper returning a single milestone by index
+    pub fn get_milestone(
+        env: Env,
+        escrow_id: u64,
+        milestone_index: u32,
+    ) -> Result<Milestone, Error> {
+        let escrow = Self::get_escrow(env, escrow_id)?;
+        escrow
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneNotFound)
+    }
+
+    /// Read-only helper listing the indices of milestones currently under dispute
+    pub fn get_disputed_milestones(env: Env, escrow_id: u64) -> Result<Vec<u32>, Error> {
+        let escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        let mut indices = Vec::new(&env);
+        for (index, milestone) in escrow.milestones.iter().enumerate() {
+            if milestone.status == MilestoneStatus::Disputed {
+                indices.push_back(index as u32);
+            }
+        }
+        Ok(indices)
+    }
+
+    /// Read-only helper returning each milestone's status in milestone order
+    pub fn get_milestone_statuses(env: Env, escrow_id: u64) -> Result<Vec<MilestoneStatus>, Error> {
+        let escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        let mut statuses = Vec::new(&env);
+        for milestone in escrow.milestones.iter() {
+            statuses.push_back(milestone.status);
+        }
+        Ok(statuses)
+    }
+
+    /// Read-only helper returning the index of the first pending milestone, if any.
+    ///
+    /// Released, disputed, cancelled and refunded milestones are skipped.
+    pub fn get_next_releasable_milestone(env: Env, escrow_id: u64) -> Result<Option<u32>, Error> {
+        let escrow = Self::get_escrow(env, escrow_id)?;
+
+        for (index, milestone) in escrow.milestones.iter().enumerate() {
+            if milestone.status == MilestoneStatus::Pending {
+                return Ok(Some(index as u32));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Read-only helper returning value-weighted progress as `(released_value, total_value)`
+    pub fn get_value_progress(env: Env, escrow_id: u64) -> Result<(i128, i128), Error> {
+        let escrow = Self::get_escrow(env, escrow_id)?;
+        Ok((escrow.total_released, escrow.total_amount))
+    }
+
+    /// Read-only helper returning the escrowed amount not yet released
+    pub fn get_remaining_amount(env: Env, escrow_id: u64) -> Result<i128, Error> {
+        let escrow = Self::get_escrow(env, escrow_id)?;
+        escrow
+            .total_amount
+            .checked_sub(escrow.total_released)
+            .ok_or(Error::InvalidMilestoneAmount)
+    }
+
+    /// Read-only helper returning the address that originally created the escrow.
+    ///
+    /// Unlike the depositor, the creator is fixed at creation and never changes.
+    pub fn get_creator(env: Env, escrow_id: u64) -> Result<Address, Error> {
+        let escrow = Self::get_escrow(env, escrow_id)?;
+        Ok(escrow.creator)
+    }
+
+    /// Read-only helper listing the ids of escrows funded by a depositor.
+    ///
+    /// Only the 100 most recent escrows are kept, oldest first.
+    pub fn get_escrows_by_depositor(env: Env, depositor: Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("dep_idx"), depositor))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Read-only helper listing the ids of escrows paying out to a recipient.
+    ///
+    /// Only the 100 most recent escrows are kept, oldest first.
+    pub fn get_escrows_by_recipient(env: Env, recipient: Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("rcp_idx"), recipient))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Read-only helper returning the ledger timestamp of the escrow's last state change
+    pub fn get_last_updated(env: Env, escrow_id: u64) -> Result<u64, Error> {
+        let escrow = Self::get_escrow(env, escrow_id)?;
+        Ok(escrow.updated_at)
+    }
+
+    /// Reports how far an escrow's share of the pooled token balance deviates
+    /// from what it should hold, for monitoring.
+    ///
+    /// The escrow should hold `total_amount - total_released` (nothing once
+    /// cancelled). Because all escrows of a token share a single contract
+    /// balance, the escrow's actual holding is estimated as its pro-rata share
+    /// of that balance by outstanding amount. A positive result is a shortfall,
+    /// a negative one a surplus (e.g. tokens sent to the contract directly).
+    /// A pooled shortfall is spread across every escrow of the token, so a
+    /// non-zero value flags the token pool rather than pinpointing the escrow.
+    pub fn get_escrow_discrepancy(env: Env, escrow_id: u64) -> Result<i128, Error> {
+        let escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        let expected = match escrow.status {
+            EscrowStatus::Cancelled => 0,
+            _ => escrow
+                .total_amount
+                .checked_sub(escrow.total_released)
+                .ok_or(Error::InvalidMilestoneAmount)?,
+        };
+
+        let total_locked = get_locked(&env, &escrow.token);
+        let share = if total_locked > 0 {
+            let balance =
+                token::Client::new(&env, &escrow.token).balance(&env.current_contract_address());
+            balance
+                .checked_mul(expected)
+                .ok_or(Error::InvalidMilestoneAmount)?
+                / total_locked
+        } else {
+            0
+        };
+
+        expected
+            .checked_sub(share)
+            .ok_or(Error::InvalidMilestoneAmount)
+    }
+
+    /// Read-only helper returning the funds the contract holds for this escrow alone
+    pub fn get_escrow_balance(env: Env, escrow_id: u64) -> Result<i128, Error> {
+        let storage_key = get_storage_key(escrow_id);
+        if !env.storage().persistent().has(&storage_key) {
+            return Err(Error::EscrowNotFound);
+        }
+        Ok(read_escrow_balance(&env, escrow_id))
+    }
+
+    /// Read-only helper returning the total the contract holds for all escrows in a token
+    pub fn get_total_locked(env: Env, token: Address) -> i128 {
+        get_locked(&env, &token)
+    }
+
+    /// Read-only helper returning what a depositor still has locked in a token.
+    ///
+    /// Equals `total_amount - total_released` summed over the depositor's
+    /// active escrows in that token. It is kept as a running total, updated
+    /// whenever funds enter or leave an escrow, so it covers every escrow
+    /// rather than only the 100 listed by `get_escrows_by_depositor`.
+    pub fn get_depositor_total_locked(env: Env, depositor: Address, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&get_depositor_locked_key(&depositor, &token))
+            .unwrap_or(0)
+    }
+
+    /// Reports whether a milestone can currently be released and, if not, why.
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `MilestoneNotFound` - If index is out of bounds
+    pub fn get_release_eligibility(
+        env: Env,
+        escrow_id: u64,
+        milestone_index: u32,
+    ) -> Result<ReleaseEligibility, Error> {
+        let escrow = Self::get_escrow(env.clone(), escrow_id)?;
+        let milestone = escrow
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneNotFound)?;
+
+        let reason = if Self::is_paused(env.clone()) {
+            ReleaseBlocker::ContractPaused
+        } else if escrow.status != EscrowStatus::Active {
+            ReleaseBlocker::EscrowNotActive
+        } else if escrow.release_blocked {
+            ReleaseBlocker::ReleaseBlocked
+        } else {
+            match milestone.status {
+                MilestoneStatus::Pending
+                    if milestone.deadline != 0 && env.ledger().timestamp() > milestone.deadline =>
+                {
+                    ReleaseBlocker::DeadlinePassed
+                }
+                MilestoneStatus::Pending => ReleaseBlocker::None,
+                MilestoneStatus::Released => ReleaseBlocker::AlreadyReleased,
+                MilestoneStatus::Cancelled => ReleaseBlocker::MilestoneCancelled,
+                MilestoneStatus::Refunded => ReleaseBlocker::MilestoneRefunded,
+                MilestoneStatus::Disputed => ReleaseBlocker::DisputePending,
+            }
+        };
+
+        Ok(ReleaseEligibility {
+            eligible: reason == ReleaseBlocker::None,
+            reason,
+        })
+    }
+
+    /// Reports whether `release_milestone` would currently succeed for a milestone.
+    ///
+    /// Combines the checks behind `get_release_eligibility` with the fee
+    /// configuration, the escrow's tracked balance and the contract's actual
+    /// token balance. Read-only.
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `MilestoneNotFound` - If index is out of bounds
+    pub fn can_release(env: Env, escrow_id: u64, milestone_index: u32) -> Result<bool, Error> {
+        if !Self::get_release_eligibility(env.clone(), escrow_id, milestone_index)?.eligible {
+            return Ok(false);
+        }
+
+        if Self::get_config(env.clone()).is_err() {
+            return Ok(false);
+        }
+
+        let escrow = Self::get_escrow(env.clone(), escrow_id)?;
+        let milestone = escrow
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneNotFound)?;
+        let remaining = milestone_remaining(&milestone)?;
+        let held = token::Client::new(&env, &escrow.token).balance(&env.current_contract_address());
+
+        Ok(remaining <= read_escrow_balance(&env, escrow_id) && remaining <= held)
+    }
+
+    /// Read-only helper returning `(depositor, recipient)` for an escrow
+    pub fn get_parties_addresses(env: Env, escrow_id: u64) -> Result<(Address, Address), Error> {
+        let escrow = Self::get_escrow(env, escrow_id)?;
+        Ok((escrow.depositor, escrow.recipient))
+    }
+
+    /// Configures the recipient to be paid in a different token via a swap contract.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Identifier of the escrow
+    /// * `payout_token` - Token the recipient should receive
+    /// * `swap_contract` - Contract implementing `SwapInterface` used for conversion
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `EscrowNotActive` - If escrow is completed or cancelled
+    pub fn set_payout_swap(
+        env: Env,
+        escrow_id: u64,
+        payout_token: Address,
+        swap_contract: Address,
+    ) -> Result<(), Error> {
+        let storage_key = get_storage_key(escrow_id);
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&storage_key)
+            .ok_or(Error::EscrowNotFound)?;
+
+        // Both parties must agree on how the recipient gets paid
+        escrow.depositor.require_auth();
+        escrow.recipient.require_auth();
+
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        escrow.payout_token = Some(payout_token);
+        escrow.swap_contract = Some(swap_contract);
+        save_escrow(&env, &storage_key, &mut escrow);
+
+        Ok(())
+    }
+
+    /// Appoints an arbiter who can resolve disputed milestones.
+    ///
+    /// The arbiter can be appointed once per escrow and is fixed from then on,
+    /// so it cannot be swapped out while a dispute is pending.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Identifier of the escrow
+    /// * `arbiter` - Address trusted by both parties to settle disputes
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `EscrowNotActive` - If escrow is completed or cancelled
+    /// * `ArbiterAlreadySet` - If the escrow already has an arbiter
+    pub fn set_arbiter(env: Env, escrow_id: u64, arbiter: Address) -> Result<(), Error> {
+        let storage_key = get_storage_key(escrow_id);
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&storage_key)
+            .ok_or(Error::EscrowNotFound)?;
+
+        // Both parties must agree on who settles their disputes
+        escrow.depositor.require_auth();
+        escrow.recipient.require_auth();
+
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        if escrow.arbiter.is_some() {
+            return Err(Error::ArbiterAlreadySet);
+        }
+
+        escrow.arbiter = Some(arbiter.clone());
+        save_escrow(&env, &storage_key, &mut escrow);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((symbol_short!("arbiter"), escrow_id), arbiter);
+
+        Ok(())
+    }
+
+    /// Points an escrow at a new recipient address before anything has been paid out.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Identifier of the escrow
+    /// * `new_recipient` - Address to receive milestone payments from now on
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `UnauthorizedAccess` - If caller is not the depositor
+    /// * `EscrowNotActive` - If escrow is completed or cancelled
+    /// * `MilestoneAlreadyReleased` - If any funds have been released
+    /// * `SelfDealing` - If the new recipient is the depositor
+    /// * `InvalidRecipient` - If the new recipient is the token or this escrow contract
+    pub fn change_recipient(env: Env, escrow_id: u64, new_recipient: Address) -> Result<(), Error> {
+        let storage_key = get_storage_key(escrow_id);
+        let mut escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        // Verify authorization
+        escrow.depositor.require_auth();
+
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        if escrow.total_released > 0 {
+            return Err(Error::MilestoneAlreadyReleased);
+        }
+
+        if new_recipient == escrow.depositor {
+            return Err(Error::SelfDealing);
+        }
+
+        if new_recipient == escrow.token || new_recipient == env.current_contract_address() {
+            return Err(Error::InvalidRecipient);
+        }
+
+        // Move the escrow between the recipients' indexes
+        unindex_escrow(
+            &env,
+            &(symbol_short!("rcp_idx"), escrow.recipient.clone()),
+            escrow_id,
+        );
+        index_escrow(
+            &env,
+            &(symbol_short!("rcp_idx"), new_recipient.clone()),
+            escrow_id,
+        );
+
+        escrow.recipient = new_recipient;
+        save_escrow(&env, &storage_key, &mut escrow);
+
+        Ok(())
+    }
+
+    /// Hands the depositor role of an escrow to a new address.
+    ///
+    /// Releases, cancellations and other depositor-only calls authorize
+    /// against the new address from then on. The previous depositor's release
+    /// signer, release operators and pending release proposals are dropped,
+    /// and the claim nonce is reset. The creator is unchanged.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Identifier of the escrow
+    /// * `new_depositor` - Address taking over the depositor role
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `UnauthorizedAccess` - If caller is not the current depositor
+    /// * `EscrowNotActive` - If escrow is completed or cancelled
+    /// * `SelfDealing` - If the new depositor is the recipient
+    /// * `InvalidRecipient` - If the new depositor is the token or this escrow contract
+    pub fn transfer_depositor(
+        env: Env,
+        escrow_id: u64,
+        new_depositor: Address,
+    ) -> Result<(), Error> {
+        let storage_key = get_storage_key(escrow_id);
+        let mut escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        // Verify authorization
+        escrow.depositor.require_auth();
+
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        if new_depositor == escrow.recipient {
+            return Err(Error::SelfDealing);
+        }
+
+        if new_depositor == escrow.token || new_depositor == env.current_contract_address() {
+            return Err(Error::InvalidRecipient);
+        }
+
+        // Move the escrow between the depositors' indexes
+        unindex_escrow(
+            &env,
+            &(symbol_short!("dep_idx"), escrow.depositor.clone()),
+            escrow_id,
+        );
+        index_escrow(
+            &env,
+            &(symbol_short!("dep_idx"), new_depositor.clone()),
+            escrow_id,
+        );
+
+        // Drop every release path the previous depositor set up
+        env.storage()
+            .persistent()
+            .remove(&(symbol_short!("signer"), escrow_id));
+        env.storage()
+            .persistent()
+            .remove(&(symbol_short!("nonce"), escrow_id));
+        for index in 0..escrow.milestones.len() {
+            env.storage()
+                .persistent()
+                .remove(&get_proposal_key(escrow_id, index));
+        }
+        escrow.release_operators = Vec::new(&env);
+
+        // Funds still held by the escrow now count towards the new depositor
+        let balance = read_escrow_balance(&env, escrow_id);
+        adjust_depositor_locked(&env, &escrow.depositor, &escrow.token, -balance)?;
+        adjust_depositor_locked(&env, &new_depositor, &escrow.token, balance)?;
+
+        let previous = escrow.depositor.clone();
+        escrow.depositor = new_depositor.clone();
+        save_escrow(&env, &storage_key, &mut escrow);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("dep_xfer"), escrow_id),
+            (previous, new_depositor),
+        );
+
+        Ok(())
+    }
+
+    /// Releases a specific milestone payment to the recipient with platform fee deduction.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Identifier of the escrow
+    /// * `milestone_index` - Index of the milestone to release
+    /// * `token_address` - Address of the escrow's token contract
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `UnauthorizedAccess` - If caller is not the depositor
+    /// * `InvalidToken` - If `token_address` is not the escrow's token
+    /// * `EscrowNotActive` - If escrow is completed or cancelled
+    /// * `MilestoneNotFound` - If index is out of bounds
+    /// * `MilestoneAlreadyReleased` - If milestone was already released
+    /// * `MilestoneNotPending` - If milestone was cancelled
+    /// * `MilestoneDisputed` - If milestone is under dispute
+    /// * `DeadlinePassed` - If the milestone's deadline has passed
+    /// * `ReleaseBlocked` - If the recipient has paused releases
+    /// * `TreasuryNotInitialized` - If contract not initialized
+    /// * `SwapFailed` - If a configured swap contract fails to convert the payout
+    /// * `BalanceMismatch` - If the escrow's tracked balance cannot cover the milestone
+    /// * `InsufficientBalance` - If the contract's token balance cannot cover the milestone
+    /// * `ContractPaused` - If fund-moving operations are paused
+    ///
+    /// # Fee Calculation
+    /// Platform fee is calculated using basis points: fee = (amount * fee_bps) / 10000
+    /// The recipient receives: amount - fee
+    /// The treasury receives: fee
+    pub fn release_milestone(
+        env: Env,
+        escrow_id: u64,
+        milestone_index: u32,
+        token_address: Address,
+    ) -> Result<(), Error> {
+        let storage_key = get_storage_key(escrow_id);
+
+        // Load escrow from storage
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&storage_key)
+            .ok_or(Error::EscrowNotFound)?;
+
+        // Verify authorization
+        escrow.depositor.require_auth();
+
+        // Funds only ever move in the token the escrow was funded with
+        if token_address != escrow.token {
+            return Err(Error::InvalidToken);
+        }
+
+        release_escrow_milestone(&env, escrow_id, escrow, milestone_index, None, true)
+    }
+
+    /// Releases several milestones in one call, paying out in the escrow's token.
+    ///
+    /// All releases succeed or none do: if any index fails, e.g. because it is
+    /// out of bounds or listed twice, the whole call reverts.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Identifier of the escrow
+    /// * `indices` - Indices of the milestones to release, at most 50
+    ///
+    /// # Errors
+    /// * `VectorTooLarge` - If more than 50 indices are given
+    /// * Any error returned by `release_milestone` for one of the indices
+    pub fn release_milestones(env: Env, escrow_id: u64, indices: Vec<u32>) -> Result<(), Error> {
+        if indices.len() > MAX_BATCH_SIZE {
+            return Err(Error::VectorTooLarge);
+        }
+
+        let escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        // Verify authorization once for the whole batch
+        escrow.depositor.require_auth();
+
+        for index in indices.iter() {
+            let escrow = Self::get_escrow(env.clone(), escrow_id)?;
+            release_escrow_milestone(&env, escrow_id, escrow, index, None, true)?;
+        }
+
+        Ok(())
+    }
+
+    /// Releases part of a milestone, e.g. to stream payment as work progresses.
+    ///
+    /// The milestone stays pending until its full amount has been paid out,
+    /// at which point it is marked released. The platform fee applies to each
+    /// partial payout.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Identifier of the escrow
+    /// * `milestone_index` - Index of the milestone to pay from
+    /// * `amount` - Amount to release, at most the milestone's unpaid remainder
+    ///
+    /// # Errors
+    /// * `ZeroAmount` - If amount is zero or negative
+    /// * `PartialAmountExceeded` - If amount exceeds the milestone's unpaid remainder
+    /// * Any error returned by `release_milestone`
+    pub fn release_partial(
+        env: Env,
+        escrow_id: u64,
+        milestone_index: u32,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        // Verify authorization
+        escrow.depositor.require_auth();
+
+        release_escrow_milestone(&env, escrow_id, escrow, milestone_index, Some(amount), true)
+    }
+
+    /// Read-only helper returning how much of a milestone has been paid out so far
+    pub fn get_milestone_released(
+        env: Env,
+        escrow_id: u64,
+        milestone_index: u32,
+    ) -> Result<i128, Error> {
+        let escrow = Self::get_escrow(env.clone(), escrow_id)?;
+        let milestone = escrow
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneNotFound)?;
+
+        Ok(milestone.released_so_far)
+    }
+
+    /// Registers the ed25519 public key allowed to sign releases for an escrow.
+    ///
+    /// Lets the depositor pre-authorize releases off-chain via `release_with_signature`.
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `UnauthorizedAccess` - If caller is not the depositor
+    pub fn set_release_signer(
+        env: Env,
+        escrow_id: u64,
+        public_key: BytesN<32>,
+    ) -> Result<(), Error> {
+        let escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        // Verify authorization
+        escrow.depositor.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("signer"), escrow_id), &public_key);
+
+        Ok(())
+    }
+
+    /// Returns the nonce the next signed release for an escrow must cover.
+    pub fn get_claim_nonce(env: Env, escrow_id: u64) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("nonce"), escrow_id))
+            .unwrap_or(0)
+    }
+
+    /// Releases a milestone using a signature pre-authorized by the depositor.
+    ///
+    /// The depositor's registered signer must have signed the XDR encoding of
+    /// `(contract_address, escrow_id, milestone_index, claim_nonce)`, which ties
+    /// the signature to this deployment. Anyone (typically the
+    /// recipient) may submit it, so the depositor doesn't need to transact.
+    /// Each accepted signature bumps the nonce, so it cannot be replayed.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Identifier of the escrow
+    /// * `milestone_index` - Index of the milestone to release
+    /// * `signature` - ed25519 signature over the release message
+    ///
+    /// # Errors
+    /// * `SignerNotSet` - If the depositor has not registered a signer
+    /// * Any error returned by `release_milestone`
+    ///
+    /// # Panics
+    /// If the signature does not verify against the registered signer.
+    pub fn release_with_signature(
+        env: Env,
+        escrow_id: u64,
+        milestone_index: u32,
+        signature: BytesN<64>,
+    ) -> Result<(), Error> {
+        let escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        let public_key: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("signer"), escrow_id))
+            .ok_or(Error::SignerNotSet)?;
+
+        // Verify the depositor signed this exact release at the current nonce
+        let nonce = Self::get_claim_nonce(env.clone(), escrow_id);
+        let message = (
+            env.current_contract_address(),
+            escrow_id,
+            milestone_index,
+            nonce,
+        )
+            .to_xdr(&env);
+        env.crypto()
+            .ed25519_verify(&public_key, &message, &signature);
+
+        // Consume the nonce so the signature cannot be replayed
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("nonce"), escrow_id), &(nonce + 1));
+
+        release_escrow_milestone(&env, escrow_id, escrow, milestone_index, None, true)
+    }
+
+    /// Allows an operator to trigger milestone releases on the depositor's behalf.
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `UnauthorizedAccess` - If caller is not the depositor
+    /// * `VectorTooLarge` - If the escrow already has 10 operators
+    pub fn add_release_operator(env: Env, escrow_id: u64, operator: Address) -> Result<(), Error> {
+        let storage_key = get_storage_key(escrow_id);
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&storage_key)
+            .ok_or(Error::EscrowNotFound)?;
+
+        // Verify authorization
+        escrow.depositor.require_auth();
+
+        if escrow.release_operators.contains(&operator) {
+            return Ok(());
+        }
+        if escrow.release_operators.len() >= MAX_RELEASE_OPERATORS {
+            return Err(Error::VectorTooLarge);
+        }
+
+        escrow.release_operators.push_back(operator);
+        save_escrow(&env, &storage_key, &mut escrow);
+
+        Ok(())
+    }
+
+    /// Revokes an operator's permission to trigger milestone releases.
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `UnauthorizedAccess` - If caller is not the depositor
+    pub fn remove_release_operator(
+        env: Env,
+        escrow_id: u64,
+        operator: Address,
+    ) -> Result<(), Error> {
+        let storage_key = get_storage_key(escrow_id);
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&storage_key)
+            .ok_or(Error::EscrowNotFound)?;
+
+        // Verify authorization
+        escrow.depositor.require_auth();
+
+        if let Some(index) = escrow.release_operators.first_index_of(&operator) {
+            escrow.release_operators.remove(index);
+            save_escrow(&env, &storage_key, &mut escrow);
+        }
+
+        Ok(())
+    }
+
+    /// Releases a milestone on behalf of the depositor by a listed release operator.
+    ///
+    /// Behaves like `release_milestone`, paying out in the escrow's token.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Identifier of the escrow
+    /// * `milestone_index` - Index of the milestone to release
+    /// * `operator` - Depositor or listed release operator triggering the release
+    ///
+    /// # Errors
+    /// * `UnauthorizedAccess` - If caller is neither the depositor nor a listed operator
+    /// * Any error returned by `release_milestone`
+    pub fn operator_release(
+        env: Env,
+        escrow_id: u64,
+        milestone_index: u32,
+        operator: Address,
+    ) -> Result<(), Error> {
+        let escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        // Verify operator authorization
+        operator.require_auth();
+        if operator != escrow.depositor && !escrow.release_operators.contains(&operator) {
+            return Err(Error::UnauthorizedAccess);
+        }
+
+        release_escrow_milestone(&env, escrow_id, escrow, milestone_index, None, true)
+    }
+
+    /// Recipient pauses all releases until the depositor acknowledges.
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `UnauthorizedAccess` - If caller is not the recipient
+    /// * `EscrowNotActive` - If escrow is completed or cancelled
+    pub fn request_pause(env: Env, escrow_id: u64) -> Result<(), Error> {
+        let storage_key = get_storage_key(escrow_id);
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&storage_key)
+            .ok_or(Error::EscrowNotFound)?;
+
+        // Verify authorization
+        escrow.recipient.require_auth();
+
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        escrow.release_blocked = true;
+        save_escrow(&env, &storage_key, &mut escrow);
+
+        Ok(())
+    }
+
+    /// Depositor responds to a recipient pause, either resuming or keeping the block.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Identifier of the escrow
+    /// * `resume` - `true` lifts the block, `false` confirms it
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `UnauthorizedAccess` - If caller is not the depositor
+    pub fn acknowledge_pause(env: Env, escrow_id: u64, resume: bool) -> Result<(), Error> {
+        let storage_key = get_storage_key(escrow_id);
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&storage_key)
+            .ok_or(Error::EscrowNotFound)?;
+
+        // Verify authorization
+        escrow.depositor.require_auth();
+
+        // Nothing to acknowledge unless the recipient requested a pause
+        if !escrow.release_blocked {
+            return Ok(());
+        }
+
+        if resume {
+            escrow.release_blocked = false;
+            save_escrow(&env, &storage_key, &mut escrow);
+        }
+
+        Ok(())
+    }
+
+    /// Depositor opts in or out of completing the escrow automatically.
+    ///
+    /// When enabled, the release that settles the last milestone also marks
+    /// the escrow as completed, so no separate `complete_escrow` call is needed.
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `UnauthorizedAccess` - If caller is not the depositor
+    /// * `EscrowNotActive` - If escrow is completed or cancelled
+    pub fn set_auto_complete(env: Env, escrow_id: u64, enabled: bool) -> Result<(), Error> {
+        let storage_key = get_storage_key(escrow_id);
+        let mut escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        // Verify authorization
+        escrow.depositor.require_auth();
+
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        escrow.auto_complete = enabled;
+        save_escrow(&env, &storage_key, &mut escrow);
+
+        Ok(())
+    }
+
+    /// Proposes releasing a milestone once the configured release delay has elapsed.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Identifier of the escrow
+    /// * `milestone_index` - Index of the milestone to release
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `UnauthorizedAccess` - If caller is not the depositor
+    /// * `EscrowNotActive` - If escrow is completed or cancelled
+    /// * `MilestoneNotFound` - If index is out of bounds
+    /// * `MilestoneNotPending` - If milestone is not pending
+    /// * `ProposalExists` - If a release is already proposed for this milestone
+    pub fn propose_release(env: Env, escrow_id: u64, milestone_index: u32) -> Result<(), Error> {
+        let escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        // Verify authorization
+        escrow.depositor.require_auth();
+
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        let milestone = escrow
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneNotFound)?;
+        if milestone.status != MilestoneStatus::Pending {
+            return Err(Error::MilestoneNotPending);
+        }
+
+        let proposal_key = get_proposal_key(escrow_id, milestone_index);
+        if env.storage().persistent().has(&proposal_key) {
+            return Err(Error::ProposalExists);
+        }
+
+        let ready_at = env
+            .ledger()
+            .timestamp()
+            .saturating_add(Self::get_release_delay(env.clone()));
+        env.storage().persistent().set(&proposal_key, &ready_at);
+
+        Ok(())
+    }
+
+    /// Returns the timestamp a proposed release becomes executable, if one exists.
+    pub fn get_proposed_release(env: Env, escrow_id: u64, milestone_index: u32) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&get_proposal_key(escrow_id, milestone_index))
+    }
+
+    /// Executes a proposed release once its delay has elapsed.
+    ///
+    /// The depositor already authorized the release when proposing it, so
+    /// anyone may execute it. Pays out in the escrow's token.
+    ///
+    /// # Errors
+    /// * `ProposalNotFound` - If no release is proposed for this milestone
+    /// * `ProposalNotReady` - If the release delay has not elapsed
+    /// * Any error returned by `release_milestone`
+    pub fn execute_release(env: Env, escrow_id: u64, milestone_index: u32) -> Result<(), Error> {
+        let escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        let proposal_key = get_proposal_key(escrow_id, milestone_index);
+        let ready_at: u64 = env
+            .storage()
+            .persistent()
+            .get(&proposal_key)
+            .ok_or(Error::ProposalNotFound)?;
+
+        if env.ledger().timestamp() < ready_at {
+            return Err(Error::ProposalNotReady);
+        }
+
+        env.storage().persistent().remove(&proposal_key);
+
+        release_escrow_milestone(&env, escrow_id, escrow, milestone_index, None, true)
+    }
+
+    /// Aborts a pending release proposal.
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `UnauthorizedAccess` - If caller is not the depositor
+    /// * `ProposalNotFound` - If no release is proposed for this milestone
+    pub fn cancel_proposed_release(
+        env: Env,
+        escrow_id: u64,
+        milestone_index: u32,
+    ) -> Result<(), Error> {
+        let escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        // Verify authorization
+        escrow.depositor.require_auth();
+
+        let proposal_key = get_proposal_key(escrow_id, milestone_index);
+        if !env.storage().persistent().has(&proposal_key) {
+            return Err(Error::ProposalNotFound);
+        }
+        env.storage().persistent().remove(&proposal_key);
+
+        Ok(())
+    }
+
+    /// Buyer confirms delivery and releases a milestone to the recipient.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Identifier of the escrow
+    /// * `milestone_index` - Index of the milestone to release
+    /// * `buyer` - Buyer address confirming the delivery
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `UnauthorizedAccess` - If caller is not the buyer/depositor
+    /// * `EscrowNotActive` - If escrow is completed or cancelled
+    /// * `MilestoneNotFound` - If index is out of bounds
+    /// * `MilestoneAlreadyReleased` - If milestone was already released
+    /// * `MilestoneNotPending` - If milestone was cancelled
+    /// * `ReleaseBlocked` - If the recipient has paused releases
+    /// * `InsufficientBalance` - If the contract's token balance cannot cover the milestone
+    /// * `ContractPaused` - If fund-moving operations are paused
+    pub fn confirm_delivery(
+        env: Env,
+        escrow_id: u64,
+        milestone_index: u32,
+        buyer: Address,
+    ) -> Result<(), Error> {
+        require_not_paused(&env)?;
+
+        let storage_key = get_storage_key(escrow_id);
+
+        // Load escrow from storage
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&storage_key)
             .ok_or(Error::EscrowNotFound)?;
 
         // Security Check: Verify buyer authorization
@@ -386,6 +1873,11 @@ impl VaultixEscrow {
             return Err(Error::EscrowNotActive);
         }
 
+        // Check the recipient hasn't paused releases
+        if escrow.release_blocked {
+            return Err(Error::ReleaseBlocked);
+        }
+
         // Verify milestone index is valid
         if milestone_index >= escrow.milestones.len() {
             return Err(Error::MilestoneNotFound);
@@ -402,31 +1894,359 @@ impl VaultixEscrow {
             return Err(Error::MilestoneAlreadyReleased);
         }
 
+        // Cancelled or refunded milestones can no longer be paid out
+        if matches!(
+            milestone.status,
+            MilestoneStatus::Cancelled | MilestoneStatus::Refunded
+        ) {
+            return Err(Error::MilestoneNotPending);
+        }
+
+        // Disputed milestones are held until the dispute is resolved
+        if milestone.status == MilestoneStatus::Disputed {
+            return Err(Error::MilestoneDisputed);
+        }
+
+        // Milestones past their own deadline can only be cancelled
+        if milestone.deadline != 0 && env.ledger().timestamp() > milestone.deadline {
+            return Err(Error::DeadlinePassed);
+        }
+
+        // Only the part not already paid out via partial releases is due
+        let amount = milestone_remaining(&milestone)?;
+
         // Update milestone status
+        milestone.released_so_far = milestone.amount;
         milestone.status = MilestoneStatus::Released;
         escrow.milestones.set(milestone_index, milestone.clone());
 
         // Update total released with overflow protection
         escrow.total_released = escrow
             .total_released
-            .checked_add(milestone.amount)
-            .ok_or(Error::InvalidMilestoneAmount)?;
+            .checked_add(amount)
+            .ok_or(Error::TotalOverflow)?;
+
+        // Move only funds this escrow actually holds
+        debit_escrow(&env, escrow_id, &escrow, amount)?;
 
         // Execute token transfer from contract to recipient
         let token_client = token::Client::new(&env, &escrow.token);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &escrow.recipient,
-            &milestone.amount,
+        if token_client.balance(&env.current_contract_address()) < amount {
+            return Err(Error::InsufficientBalance);
+        }
+        token_client.transfer(&env.current_contract_address(), &escrow.recipient, &amount);
+
+        // Finish the escrow right away if the depositor opted in
+        if escrow.auto_complete && verify_all_released(&escrow.milestones) {
+            escrow.status = EscrowStatus::Completed;
+        }
+
+        // Save updated escrow
+        save_escrow(&env, &storage_key, &mut escrow);
+
+        Ok(())
+    }
+
+    /// Flags a pending milestone as disputed, blocking its release until resolved.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Identifier of the escrow
+    /// * `milestone_index` - Index of the milestone to dispute
+    /// * `caller` - Depositor or recipient raising the dispute
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `UnauthorizedAccess` - If caller is neither depositor nor recipient
+    /// * `EscrowNotActive` - If escrow is completed or cancelled
+    /// * `MilestoneNotFound` - If index is out of bounds
+    /// * `MilestoneAlreadyReleased` - If the milestone was already released
+    /// * `MilestoneNotPending` - If the milestone is cancelled or already disputed
+    pub fn raise_dispute(
+        env: Env,
+        escrow_id: u64,
+        milestone_index: u32,
+        caller: Address,
+    ) -> Result<(), Error> {
+        let storage_key = get_storage_key(escrow_id);
+        let mut escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        // Verify authorization
+        caller.require_auth();
+        if caller != escrow.depositor && caller != escrow.recipient {
+            return Err(Error::UnauthorizedAccess);
+        }
+
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        let mut milestone = escrow
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneNotFound)?;
+
+        match milestone.status {
+            MilestoneStatus::Pending => {}
+            MilestoneStatus::Released => return Err(Error::MilestoneAlreadyReleased),
+            _ => return Err(Error::MilestoneNotPending),
+        }
+
+        milestone.status = MilestoneStatus::Disputed;
+        escrow.milestones.set(milestone_index, milestone);
+
+        save_escrow(&env, &storage_key, &mut escrow);
+        record_dispute_transition(&env, escrow_id, milestone_index, MilestoneStatus::Disputed);
+
+        // Emit event for the dispute
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("disputed"), escrow_id, milestone_index),
+            caller,
         );
 
+        Ok(())
+    }
+
+    /// Returns the `(timestamp, status)` transitions a milestone went through in a dispute.
+    ///
+    /// The first entry is the move to `Disputed`; once resolved, the outcome
+    /// (`Released` or `Cancelled`) follows. Empty if never disputed.
+    pub fn get_dispute_history(
+        env: Env,
+        escrow_id: u64,
+        milestone_index: u32,
+    ) -> Vec<(u64, MilestoneStatus)> {
+        env.storage()
+            .persistent()
+            .get(&get_dispute_history_key(escrow_id, milestone_index))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Settles a disputed milestone (arbiter only).
+    ///
+    /// In the recipient's favour the milestone is released as normal, platform
+    /// fee included. Otherwise its amount is refunded to the depositor and the
+    /// milestone is cancelled.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Identifier of the escrow
+    /// * `milestone_index` - Index of the disputed milestone
+    /// * `release_to_recipient` - Whether the recipient is paid
+    /// * `arbiter` - Arbiter settling the dispute
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `ArbiterNotSet` - If no arbiter was appointed
+    /// * `UnauthorizedAccess` - If caller is not the arbiter
+    /// * `EscrowNotActive` - If escrow is completed or cancelled
+    /// * `MilestoneNotFound` - If index is out of bounds
+    /// * `InvalidStatusTransition` - If the milestone is not disputed
+    /// * `ContractPaused` - If fund-moving operations are paused
+    pub fn resolve_dispute(
+        env: Env,
+        escrow_id: u64,
+        milestone_index: u32,
+        release_to_recipient: bool,
+        arbiter: Address,
+    ) -> Result<(), Error> {
+        require_not_paused(&env)?;
+
+        let storage_key = get_storage_key(escrow_id);
+        let mut escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        // Verify authorization
+        arbiter.require_auth();
+        match &escrow.arbiter {
+            None => return Err(Error::ArbiterNotSet),
+            Some(appointed) if *appointed != arbiter => return Err(Error::UnauthorizedAccess),
+            Some(_) => {}
+        }
+
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        let mut milestone = escrow
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneNotFound)?;
+        if milestone.status != MilestoneStatus::Disputed {
+            return Err(Error::InvalidStatusTransition);
+        }
+
+        if release_to_recipient {
+            milestone.status = MilestoneStatus::Pending;
+            escrow.milestones.set(milestone_index, milestone);
+
+            // The arbiter can still award a milestone whose deadline ran out mid-dispute
+            release_escrow_milestone(&env, escrow_id, escrow, milestone_index, None, false)?;
+            record_dispute_transition(&env, escrow_id, milestone_index, MilestoneStatus::Released);
+            publish_dispute_resolved(&env, escrow_id, milestone_index, true);
+
+            return Ok(());
+        }
+
+        let refund = milestone_remaining(&milestone)?;
+        milestone.status = MilestoneStatus::Cancelled;
+        escrow.milestones.set(milestone_index, milestone);
+        escrow.total_amount = escrow
+            .total_amount
+            .checked_sub(refund)
+            .ok_or(Error::InvalidMilestoneAmount)?;
+
+        // Save updated escrow
+        save_escrow(&env, &storage_key, &mut escrow);
+
+        // Refund the disputed amount to the depositor
+        debit_escrow(&env, escrow_id, &escrow, refund)?;
+        let token_client = token::Client::new(&env, &escrow.token);
+        token_client.transfer(&env.current_contract_address(), &escrow.depositor, &refund);
+
+        record_dispute_transition(&env, escrow_id, milestone_index, MilestoneStatus::Cancelled);
+        publish_dispute_resolved(&env, escrow_id, milestone_index, false);
+
+        Ok(())
+    }
+
+    /// Refunds a single pending milestone to the depositor, e.g. when a task is descoped.
+    ///
+    /// The milestone is marked `Refunded` and `total_amount` is reduced by the
+    /// refund; the rest of the escrow is unaffected.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Identifier of the escrow
+    /// * `milestone_index` - Index of the `Pending` milestone to refund
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `UnauthorizedAccess` - If caller is not the depositor
+    /// * `EscrowNotActive` - If escrow is completed or cancelled
+    /// * `MilestoneNotFound` - If index is out of bounds
+    /// * `MilestoneAlreadyReleased` - If the milestone was already released
+    /// * `MilestoneNotPending` - If the milestone is not pending
+    /// * `ContractPaused` - If fund-moving operations are paused
+    pub fn refund_milestone(env: Env, escrow_id: u64, milestone_index: u32) -> Result<(), Error> {
+        require_not_paused(&env)?;
+
+        let storage_key = get_storage_key(escrow_id);
+        let mut escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        // Verify authorization
+        escrow.depositor.require_auth();
+
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        let mut milestone = escrow
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneNotFound)?;
+
+        match milestone.status {
+            MilestoneStatus::Pending => {}
+            MilestoneStatus::Released => return Err(Error::MilestoneAlreadyReleased),
+            _ => return Err(Error::MilestoneNotPending),
+        }
+
+        let refund = milestone_remaining(&milestone)?;
+        milestone.status = MilestoneStatus::Refunded;
+        escrow.milestones.set(milestone_index, milestone);
+        escrow.total_amount = escrow
+            .total_amount
+            .checked_sub(refund)
+            .ok_or(Error::InvalidMilestoneAmount)?;
+
+        // Save updated escrow
+        save_escrow(&env, &storage_key, &mut escrow);
+
+        // Return the milestone's unpaid amount to the depositor
+        debit_escrow(&env, escrow_id, &escrow, refund)?;
+        let token_client = token::Client::new(&env, &escrow.token);
+        token_client.transfer(&env.current_contract_address(), &escrow.depositor, &refund);
+
+        Ok(())
+    }
+
+    /// Cancels specific unreleased milestones and refunds their amounts to the depositor.
+    ///
+    /// The remaining milestones stay active and `total_amount` is reduced by the refund.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Identifier of the escrow
+    /// * `indices` - Indices of the `Pending` milestones to cancel
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `UnauthorizedAccess` - If caller is not the depositor
+    /// * `EscrowNotActive` - If escrow is completed or cancelled
+    /// * `MilestoneNotFound` - If any index is out of bounds
+    /// * `MilestoneAlreadyReleased` - If any milestone was already released
+    /// * `MilestoneNotPending` - If any milestone is not pending (e.g. listed twice)
+    /// * `ContractPaused` - If fund-moving operations are paused
+    pub fn cancel_milestones(env: Env, escrow_id: u64, indices: Vec<u32>) -> Result<(), Error> {
+        require_not_paused(&env)?;
+
+        let storage_key = get_storage_key(escrow_id);
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&storage_key)
+            .ok_or(Error::EscrowNotFound)?;
+
+        // Verify authorization
+        escrow.depositor.require_auth();
+
+        // Check escrow is active
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        let mut refund: i128 = 0;
+        for index in indices.iter() {
+            let mut milestone = escrow
+                .milestones
+                .get(index)
+                .ok_or(Error::MilestoneNotFound)?;
+
+            match milestone.status {
+                MilestoneStatus::Pending => {}
+                MilestoneStatus::Released => return Err(Error::MilestoneAlreadyReleased),
+                _ => return Err(Error::MilestoneNotPending),
+            }
+
+            refund = refund
+                .checked_add(milestone_remaining(&milestone)?)
+                .ok_or(Error::InvalidMilestoneAmount)?;
+
+            milestone.status = MilestoneStatus::Cancelled;
+            escrow.milestones.set(index, milestone);
+        }
+
+        escrow.total_amount = escrow
+            .total_amount
+            .checked_sub(refund)
+            .ok_or(Error::InvalidMilestoneAmount)?;
+
         // Save updated escrow
-        env.storage().persistent().set(&storage_key, &escrow);
+        save_escrow(&env, &storage_key, &mut escrow);
+
+        // Refund the cancelled amounts to the depositor
+        if refund > 0 {
+            debit_escrow(&env, escrow_id, &escrow, refund)?;
+            let token_client = token::Client::new(&env, &escrow.token);
+            token_client.transfer(&env.current_contract_address(), &escrow.depositor, &refund);
+        }
 
         Ok(())
     }
 
-    /// Cancels an escrow before any milestones are released.
+    /// Cancels an escrow before any milestones are released and refunds the depositor.
+    ///
+    /// If a cancellation fee is configured, it is deducted from the refund and
+    /// sent to the treasury. Cancelling an escrow with nothing left to refund
+    /// charges no fee.
     ///
     /// # Arguments
     /// * `escrow_id` - Identifier of the escrow
@@ -434,8 +2254,12 @@ impl VaultixEscrow {
     /// # Errors
     /// * `EscrowNotFound` - If escrow doesn't exist
     /// * `UnauthorizedAccess` - If caller is not the depositor
+    /// * `EscrowNotActive` - If escrow is completed or cancelled
     /// * `MilestoneAlreadyReleased` - If any milestone has been released
+    /// * `ContractPaused` - If fund-moving operations are paused
     pub fn cancel_escrow(env: Env, escrow_id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+
         let storage_key = get_storage_key(escrow_id);
 
         let mut escrow: Escrow = env
@@ -447,6 +2271,11 @@ impl VaultixEscrow {
         // Verify authorization
         escrow.depositor.require_auth();
 
+        // Check escrow is active
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
         // Verify no milestones have been released
         if escrow.total_released > 0 {
             return Err(Error::MilestoneAlreadyReleased);
@@ -454,7 +2283,212 @@ impl VaultixEscrow {
 
         // Update status
         escrow.status = EscrowStatus::Cancelled;
-        env.storage().persistent().set(&storage_key, &escrow);
+        save_escrow(&env, &storage_key, &mut escrow);
+
+        // Refund the escrowed amount, minus the cancellation fee if one applies
+        refund_on_cancel(&env, escrow_id, &escrow, escrow.total_amount)
+    }
+
+    /// Cancels an escrow after some milestones were released, refunding the rest.
+    ///
+    /// Released milestones stay paid; every other milestone is cancelled and the
+    /// unreleased remainder (`total_amount - total_released`) goes back to the
+    /// depositor, minus the cancellation fee if one applies.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Identifier of the escrow
+    ///
+    /// # Errors
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `UnauthorizedAccess` - If caller is not the depositor
+    /// * `EscrowNotActive` - If escrow is completed or cancelled
+    /// * `MilestoneDisputed` - If a milestone is under dispute
+    /// * `ContractPaused` - If fund-moving operations are paused
+    pub fn force_cancel(env: Env, escrow_id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+
+        let storage_key = get_storage_key(escrow_id);
+        let mut escrow = Self::get_escrow(env.clone(), escrow_id)?;
+
+        // Verify authorization
+        escrow.depositor.require_auth();
+
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        for index in 0..escrow.milestones.len() {
+            let mut milestone = escrow
+                .milestones
+                .get(index)
+                .ok_or(Error::MilestoneNotFound)?;
+            match milestone.status {
+                MilestoneStatus::Pending => {
+                    milestone.status = MilestoneStatus::Cancelled;
+                    escrow.milestones.set(index, milestone);
+                }
+                // Disputes must be settled before the depositor can walk away
+                MilestoneStatus::Disputed => return Err(Error::MilestoneDisputed),
+                _ => {}
+            }
+        }
+
+        let refund = escrow
+            .total_amount
+            .checked_sub(escrow.total_released)
+            .ok_or(Error::InvalidMilestoneAmount)?;
+
+        escrow.status = EscrowStatus::Cancelled;
+        save_escrow(&env, &storage_key, &mut escrow);
+
+        refund_on_cancel(&env, escrow_id, &escrow, refund)
+    }
+
+    /// Forces a stuck escrow into a terminal state (admin only).
+    ///
+    /// Only `Active` escrows can be overridden, and only to a terminal state.
+    /// To avoid stranding funds, forcing `Cancelled` refunds the escrow's
+    /// remaining balance to the depositor, and forcing `Completed` is only
+    /// allowed once the escrow holds no funds.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Identifier of the escrow
+    /// * `status` - Target status (`Completed` or `Cancelled`)
+    ///
+    /// # Errors
+    /// * `TreasuryNotInitialized` - If contract not initialized
+    /// * `UnauthorizedAccess` - If caller is not the admin
+    /// * `EscrowNotFound` - If escrow doesn't exist
+    /// * `EscrowNotActive` - If escrow is already completed or cancelled
+    /// * `InvalidStatusTransition` - If the target is not terminal, or completing
+    ///   would leave funds locked in the escrow
+    pub fn admin_set_status(env: Env, escrow_id: u64, status: EscrowStatus) -> Result<(), Error> {
+        let admin = require_admin(&env)?;
+        force_status(&env, &admin, escrow_id, status)
+    }
+
+    /// Batch wrapper around `admin_set_status` applying the same target status.
+    ///
+    /// The whole batch reverts if any escrow cannot be transitioned.
+    ///
+    /// # Errors
+    /// * `VectorTooLarge` - If more than 50 escrow ids are provided
+    /// * Any error returned by `admin_set_status`
+    pub fn admin_set_statuses(
+        env: Env,
+        escrow_ids: Vec<u64>,
+        status: EscrowStatus,
+    ) -> Result<(), Error> {
+        if escrow_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::VectorTooLarge);
+        }
+
+        let admin = require_admin(&env)?;
+        for escrow_id in escrow_ids.iter() {
+            force_status(&env, &admin, escrow_id, status)?;
+        }
+
+        Ok(())
+    }
+
+    /// Imports a fully-formed escrow, e.g. when migrating from a legacy contract (admin only).
+    ///
+    /// The escrow is written as-is after checking it is internally consistent.
+    /// The escrow's outstanding funds (`total_amount - total_released` for an
+    /// active escrow) are pulled from the admin in the escrow's token and
+    /// credited to the escrow's balance, so an import never draws on funds
+    /// held for other escrows.
+    ///
+    /// # Arguments
+    /// * `escrow_id` - Unused identifier to import the escrow under
+    /// * `escrow` - Complete escrow state to import
+    ///
+    /// # Errors
+    /// * `TreasuryNotInitialized` - If contract not initialized
+    /// * `UnauthorizedAccess` - If caller is not the admin
+    /// * `EscrowAlreadyExists` - If escrow_id is already in use
+    /// * `SelfDealing` - If depositor and recipient are the same address
+    /// * `VectorTooLarge` - If more milestones than the configured cap (default 20) provided
+    /// * `ZeroAmount` - If any milestone amount is zero or negative
+    /// * `TotalAmountMismatch` - If totals disagree with the milestones
+    /// * `ContractSunset` - If the contract no longer accepts new escrows
+    pub fn import_escrow(env: Env, escrow_id: u64, escrow: Escrow) -> Result<(), Error> {
+        let admin = require_admin(&env)?;
+
+        if Self::is_sunset(env.clone()) {
+            return Err(Error::ContractSunset);
+        }
+
+        let storage_key = get_storage_key(escrow_id);
+        if env.storage().persistent().has(&storage_key) {
+            return Err(Error::EscrowAlreadyExists);
+        }
+
+        if escrow.depositor == escrow.recipient {
+            return Err(Error::SelfDealing);
+        }
+
+        if escrow.milestones.len() > Self::get_max_milestones(env.clone()) {
+            return Err(Error::VectorTooLarge);
+        }
+
+        // Totals must match the milestones: only the paid-out part of cancelled and
+        // refunded milestones stays in total_amount, and every milestone's
+        // released_so_far makes up total_released
+        let mut total: i128 = 0;
+        let mut released: i128 = 0;
+        for milestone in escrow.milestones.iter() {
+            if milestone.amount <= 0 {
+                return Err(Error::ZeroAmount);
+            }
+            if milestone.released_so_far < 0
+                || milestone.released_so_far > milestone.amount
+                || (milestone.status == MilestoneStatus::Released
+                    && milestone.released_so_far != milestone.amount)
+            {
+                return Err(Error::TotalAmountMismatch);
+            }
+            let counted = match milestone.status {
+                MilestoneStatus::Cancelled | MilestoneStatus::Refunded => milestone.released_so_far,
+                _ => milestone.amount,
+            };
+            total = total.checked_add(counted).ok_or(Error::TotalOverflow)?;
+            released = released
+                .checked_add(milestone.released_so_far)
+                .ok_or(Error::TotalOverflow)?;
+        }
+        if total != escrow.total_amount
+            || released != escrow.total_released
+            || escrow.total_released > escrow.total_amount
+        {
+            return Err(Error::TotalAmountMismatch);
+        }
+
+        let outstanding = match escrow.status {
+            EscrowStatus::Active => escrow.total_amount - escrow.total_released,
+            _ => 0,
+        };
+
+        let mut escrow = escrow;
+        save_escrow(&env, &storage_key, &mut escrow);
+
+        // Fund the escrow before crediting it
+        if outstanding > 0 {
+            let token_client = token::Client::new(&env, &escrow.token);
+            token_client.transfer(&admin, env.current_contract_address(), &outstanding);
+        }
+        credit_escrow(&env, escrow_id, &escrow, outstanding)?;
+
+        index_escrow(
+            &env,
+            &(symbol_short!("dep_idx"), escrow.depositor.clone()),
+            escrow_id,
+        );
+        index_escrow(
+            &env,
+            &(symbol_short!("rcp_idx"), escrow.recipient.clone()),
+            escrow_id,
+        );
 
         Ok(())
     }
@@ -487,21 +2521,652 @@ impl VaultixEscrow {
 
         // Update status
         escrow.status = EscrowStatus::Completed;
-        env.storage().persistent().set(&storage_key, &escrow);
+        save_escrow(&env, &storage_key, &mut escrow);
 
         Ok(())
     }
 }
 
+// Loads the contract admin and requires its authorization
+fn require_admin(env: &Env) -> Result<Address, Error> {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&symbol_short!("admin"))
+        .ok_or(Error::TreasuryNotInitialized)?;
+
+    admin.require_auth();
+
+    Ok(admin)
+}
+
+// Refunds a cancelled escrow's remaining funds, sending any cancellation fee to the treasury
+fn refund_on_cancel(env: &Env, escrow_id: u64, escrow: &Escrow, refund: i128) -> Result<(), Error> {
+    if refund <= 0 {
+        return Ok(());
+    }
+
+    debit_escrow(env, escrow_id, escrow, refund)?;
+    let token_client = token::Client::new(env, &escrow.token);
+
+    let treasury: Option<Address> = env.storage().instance().get(&symbol_short!("treasury"));
+    let fee = match treasury {
+        Some(treasury) => {
+            let fee = calculate_fee(refund, VaultixEscrow::get_cancel_fee(env.clone()))?;
+            if fee > 0 {
+                token_client.transfer(&env.current_contract_address(), &treasury, &fee);
+                record_fee(env, &treasury, fee)?;
+
+                // Emit event for fee collection
+                #[allow(deprecated)]
+                env.events()
+                    .publish((symbol_short!("cncl_fee"), escrow_id), (fee, treasury));
+            }
+            fee
+        }
+        None => 0,
+    };
+
+    let payout = refund
+        .checked_sub(fee)
+        .ok_or(Error::InvalidMilestoneAmount)?;
+    token_client.transfer(&env.current_contract_address(), &escrow.depositor, &payout);
+
+    Ok(())
+}
+
+// Fails with `ContractPaused` while the admin has halted fund movements
+fn require_not_paused(env: &Env) -> Result<(), Error> {
+    if VaultixEscrow::is_paused(env.clone()) {
+        return Err(Error::ContractPaused);
+    }
+    Ok(())
+}
+
+// Validates, stores and funds a new escrow for an already-authorized depositor
+fn open_escrow(
+    env: &Env,
+    escrow_id: u64,
+    depositor: Address,
+    recipient: Address,
+    milestones: Vec<Milestone>,
+    token: Address,
+) -> Result<(), Error> {
+    if VaultixEscrow::is_sunset(env.clone()) {
+        return Err(Error::ContractSunset);
+    }
+    require_not_paused(env)?;
+
+    // Validate no self-dealing (depositor cannot be recipient)
+    if depositor == recipient {
+        return Err(Error::SelfDealing);
+    }
+
+    // Reject recipients that would misroute funds back into contracts
+    if recipient == token || recipient == env.current_contract_address() {
+        return Err(Error::InvalidRecipient);
+    }
+
+    // Check if escrow already exists
+    let storage_key = get_storage_key(escrow_id);
+    if env.storage().persistent().has(&storage_key) {
+        return Err(Error::EscrowAlreadyExists);
+    }
+
+    // Validate milestones and calculate total
+    let total_amount = validate_milestones(env, &milestones)?;
+
+    // Probe the token so a missing or non-token contract fails cleanly
+    let token_client = token::Client::new(env, &token);
+    let decimals = token_decimals(env, &token)?;
+
+    // Enforce the admin-configured minimum milestone size, in whole token units
+    let min_units = VaultixEscrow::get_min_milestone_units(env.clone());
+    if min_units > 0 {
+        let min_amount = 10i128
+            .checked_pow(decimals)
+            .and_then(|scale| scale.checked_mul(min_units as i128))
+            .ok_or(Error::BelowMinimum)?;
+        for milestone in milestones.iter() {
+            if milestone.amount < min_amount {
+                return Err(Error::BelowMinimum);
+            }
+        }
+    }
+
+    // Initialize all milestones to Pending status
+    let mut initialized_milestones = Vec::new(env);
+    for milestone in milestones.iter() {
+        let mut m = milestone.clone();
+        m.status = MilestoneStatus::Pending;
+        initialized_milestones.push_back(m);
+    }
+
+    // Create the escrow
+    let mut escrow = Escrow {
+        creator: depositor.clone(),
+        depositor: depositor.clone(),
+        recipient,
+        total_amount,
+        total_released: 0,
+        milestones: initialized_milestones,
+        token: token.clone(),
+        status: EscrowStatus::Active,
+        payout_token: None,
+        swap_contract: None,
+        updated_at: env.ledger().timestamp(),
+        release_operators: Vec::new(env),
+        release_blocked: false,
+        arbiter: None,
+        auto_complete: false,
+    };
+
+    // Save to persistent storage
+    save_escrow(env, &storage_key, &mut escrow);
+
+    // Transfer funds from depositor to contract
+    token_client.transfer(&depositor, env.current_contract_address(), &total_amount);
+    credit_escrow(env, escrow_id, &escrow, total_amount)?;
+
+    index_escrow(
+        env,
+        &(symbol_short!("dep_idx"), depositor.clone()),
+        escrow_id,
+    );
+    index_escrow(
+        env,
+        &(symbol_short!("rcp_idx"), escrow.recipient.clone()),
+        escrow_id,
+    );
+
+    // Emit event for escrow creation
+    #[allow(deprecated)]
+    env.events().publish(
+        (symbol_short!("created"), escrow_id),
+        (depositor, escrow.recipient, token, total_amount),
+    );
+
+    Ok(())
+}
+
+// Opens an escrow under the next free auto-generated id and returns that id
+fn open_escrow_auto(
+    env: &Env,
+    depositor: Address,
+    recipient: Address,
+    milestones: Vec<Milestone>,
+    token: Address,
+) -> Result<u64, Error> {
+    let mut escrow_id: u64 = env
+        .storage()
+        .instance()
+        .get(&symbol_short!("counter"))
+        .unwrap_or(0);
+    while env.storage().persistent().has(&get_storage_key(escrow_id)) {
+        escrow_id = escrow_id.checked_add(1).ok_or(Error::EscrowAlreadyExists)?;
+    }
+
+    open_escrow(env, escrow_id, depositor, recipient, milestones, token)?;
+
+    let next_id = escrow_id.checked_add(1).ok_or(Error::EscrowAlreadyExists)?;
+    env.storage()
+        .instance()
+        .set(&symbol_short!("counter"), &next_id);
+
+    Ok(escrow_id)
+}
+
+// Applies an admin status override, refunding or guarding remaining funds
+fn force_status(
+    env: &Env,
+    admin: &Address,
+    escrow_id: u64,
+    status: EscrowStatus,
+) -> Result<(), Error> {
+    let storage_key = get_storage_key(escrow_id);
+
+    let mut escrow: Escrow = env
+        .storage()
+        .persistent()
+        .get(&storage_key)
+        .ok_or(Error::EscrowNotFound)?;
+
+    if escrow.status != EscrowStatus::Active {
+        return Err(Error::EscrowNotActive);
+    }
+
+    let balance = read_escrow_balance(env, escrow_id);
+    match status {
+        EscrowStatus::Cancelled => {
+            // Return whatever the escrow still holds to the depositor
+            if balance > 0 {
+                debit_escrow(env, escrow_id, &escrow, balance)?;
+                let token_client = token::Client::new(env, &escrow.token);
+                token_client.transfer(&env.current_contract_address(), &escrow.depositor, &balance);
+            }
+        }
+        EscrowStatus::Completed => {
+            if balance > 0 {
+                return Err(Error::InvalidStatusTransition);
+            }
+        }
+        EscrowStatus::Active => return Err(Error::InvalidStatusTransition),
+    }
+
+    let previous = escrow.status;
+    escrow.status = status;
+    save_escrow(env, &storage_key, &mut escrow);
+
+    // Emit event recording the admin override
+    #[allow(deprecated)]
+    env.events().publish(
+        (symbol_short!("admin_st"), escrow_id),
+        (previous, status, admin.clone()),
+    );
+
+    Ok(())
+}
+
+// Releases a milestone of an already-authorized escrow, deducting the platform fee.
+// With `amount` set only that part of the milestone is paid out; the milestone
+// is marked released once nothing remains.
+// `enforce_deadline` is false only for arbiter-driven releases.
+fn release_escrow_milestone(
+    env: &Env,
+    escrow_id: u64,
+    mut escrow: Escrow,
+    milestone_index: u32,
+    amount: Option<i128>,
+    enforce_deadline: bool,
+) -> Result<(), Error> {
+    require_not_paused(env)?;
+
+    let storage_key = get_storage_key(escrow_id);
+
+    // Check escrow is active
+    if escrow.status != EscrowStatus::Active {
+        return Err(Error::EscrowNotActive);
+    }
+
+    // Check the recipient hasn't paused releases
+    if escrow.release_blocked {
+        return Err(Error::ReleaseBlocked);
+    }
+
+    // Verify milestone index is valid
+    if milestone_index >= escrow.milestones.len() {
+        return Err(Error::MilestoneNotFound);
+    }
+
+    // Get the milestone
+    let mut milestone = escrow
+        .milestones
+        .get(milestone_index)
+        .ok_or(Error::MilestoneNotFound)?;
+
+    // Check if already released
+    if milestone.status == MilestoneStatus::Released {
+        return Err(Error::MilestoneAlreadyReleased);
+    }
+
+    // Cancelled or refunded milestones can no longer be paid out
+    if matches!(
+        milestone.status,
+        MilestoneStatus::Cancelled | MilestoneStatus::Refunded
+    ) {
+        return Err(Error::MilestoneNotPending);
+    }
+
+    // Disputed milestones are held until the dispute is resolved
+    if milestone.status == MilestoneStatus::Disputed {
+        return Err(Error::MilestoneDisputed);
+    }
+
+    // Milestones past their own deadline can only be cancelled, unless an
+    // arbiter is settling a dispute
+    if enforce_deadline && milestone.deadline != 0 && env.ledger().timestamp() > milestone.deadline
+    {
+        return Err(Error::DeadlinePassed);
+    }
+
+    // Work out how much of the milestone is paid out now
+    let remaining = milestone_remaining(&milestone)?;
+    let amount = match amount {
+        None => remaining,
+        Some(amount) if amount <= 0 => return Err(Error::ZeroAmount),
+        Some(amount) if amount > remaining => return Err(Error::PartialAmountExceeded),
+        Some(amount) => amount,
+    };
+
+    // Get treasury and fee configuration
+    let (treasury, fee_bps) = VaultixEscrow::get_config(env.clone())?;
+
+    // Calculate platform fee using integer math
+    // fee = (amount * fee_bps) / 10000
+    let fee = calculate_fee(amount, fee_bps)?;
+    let payout = amount
+        .checked_sub(fee)
+        .ok_or(Error::InvalidMilestoneAmount)?;
+
+    // Move only funds this escrow actually holds
+    debit_escrow(env, escrow_id, &escrow, amount)?;
+
+    // Create token client for transfers
+    let token_client = token::TokenClient::new(env, &escrow.token);
+
+    // Revert clearly if the contract's real balance of the escrow token has fallen short
+    if token_client.balance(&env.current_contract_address()) < amount {
+        return Err(Error::InsufficientBalance);
+    }
+
+    // Transfer payout to recipient (seller), converting it first if a swap is configured
+    match (&escrow.payout_token, &escrow.swap_contract) {
+        (Some(payout_token), Some(swap_contract)) => {
+            token_client.transfer(&env.current_contract_address(), swap_contract, &payout);
+
+            // Any swap failure reverts the whole release, including the transfer above
+            let payout_client = token::Client::new(env, payout_token);
+            let balance_before = payout_client.balance(&escrow.recipient);
+            let swap_client = SwapClient::new(env, swap_contract);
+            let amount_out =
+                match swap_client.try_swap(&escrow.token, payout_token, &payout, &escrow.recipient)
+                {
+                    Ok(Ok(amount_out)) => amount_out,
+                    _ => return Err(Error::SwapFailed),
+                };
+
+            // The recipient must actually have received what the swap reports
+            let received = payout_client
+                .balance(&escrow.recipient)
+                .checked_sub(balance_before)
+                .ok_or(Error::SwapFailed)?;
+            if amount_out <= 0 || received < amount_out {
+                return Err(Error::SwapFailed);
+            }
+        }
+        _ => {
+            token_client.transfer(&env.current_contract_address(), &escrow.recipient, &payout);
+        }
+    }
+
+    // Transfer fee to treasury (only if fee > 0)
+    if fee > 0 {
+        token_client.transfer(&env.current_contract_address(), &treasury, &fee);
+        record_fee(env, &treasury, fee)?;
+
+        // Emit event for fee collection
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("fee_coll"), escrow_id, milestone_index),
+            (fee, treasury.clone()),
+        );
+    }
+
+    // Update total released with overflow protection
+    escrow.total_released = escrow
+        .total_released
+        .checked_add(amount)
+        .ok_or(Error::TotalOverflow)?;
+
+    // Track partial payouts until the milestone is fully paid
+    milestone.released_so_far = milestone
+        .released_so_far
+        .checked_add(amount)
+        .ok_or(Error::InvalidMilestoneAmount)?;
+    if amount < remaining {
+        escrow.milestones.set(milestone_index, milestone);
+        save_escrow(env, &storage_key, &mut escrow);
+
+        // Emit event for partial milestone release
+        #[allow(deprecated)]
+        env.events().publish(
+            (symbol_short!("partial"), escrow_id, milestone_index),
+            (amount, escrow.recipient.clone(), escrow.total_released),
+        );
+
+        return Ok(());
+    }
+
+    // Update milestone status
+    milestone.status = MilestoneStatus::Released;
+    escrow.milestones.set(milestone_index, milestone.clone());
+
+    // Finish the escrow right away if the depositor opted in
+    if escrow.auto_complete && verify_all_released(&escrow.milestones) {
+        escrow.status = EscrowStatus::Completed;
+    }
+
+    // Save updated escrow
+    save_escrow(env, &storage_key, &mut escrow);
+
+    // Emit event for milestone release; amounts are gross of the platform fee
+    // so they add up to total_released
+    #[allow(deprecated)]
+    env.events().publish(
+        (symbol_short!("released"), escrow_id, milestone_index),
+        (amount, escrow.recipient.clone(), escrow.total_released),
+    );
+
+    Ok(())
+}
+
+// Amount of a milestone not yet paid out
+fn milestone_remaining(milestone: &Milestone) -> Result<i128, Error> {
+    milestone
+        .amount
+        .checked_sub(milestone.released_so_far)
+        .ok_or(Error::InvalidMilestoneAmount)
+}
+
 // Helper function to generate storage key
 fn get_storage_key(escrow_id: u64) -> (Symbol, u64) {
     (symbol_short!("escrow"), escrow_id)
 }
 
+// Resolves the deterministic address of the native XLM Stellar Asset Contract
+fn native_token_address(env: &Env) -> Address {
+    env.deployer()
+        .with_stellar_asset(Bytes::from_array(env, &NATIVE_ASSET_XDR))
+        .deployed_address()
+}
+
+// Returns the total amount the contract holds on behalf of escrows for a token
+fn get_locked(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("locked"), token.clone()))
+        .unwrap_or(0)
+}
+
+// Adjusts the total amount the contract holds on behalf of escrows for a token
+fn adjust_locked(env: &Env, token: &Address, delta: i128) -> Result<(), Error> {
+    let locked = get_locked(env, token)
+        .checked_add(delta)
+        .ok_or(Error::InvalidMilestoneAmount)?;
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("locked"), token.clone()), &locked);
+    Ok(())
+}
+
+// Storage key for the running total a depositor has locked in a token
+fn get_depositor_locked_key(depositor: &Address, token: &Address) -> (Symbol, Address, Address) {
+    (symbol_short!("dep_lock"), depositor.clone(), token.clone())
+}
+
+// Moves a depositor's locked total in a token by `delta`
+fn adjust_depositor_locked(
+    env: &Env,
+    depositor: &Address,
+    token: &Address,
+    delta: i128,
+) -> Result<(), Error> {
+    let key = get_depositor_locked_key(depositor, token);
+    let locked = env
+        .storage()
+        .persistent()
+        .get::<_, i128>(&key)
+        .unwrap_or(0)
+        .checked_add(delta)
+        .ok_or(Error::InvalidMilestoneAmount)?;
+    env.storage().persistent().set(&key, &locked);
+    Ok(())
+}
+
+// Storage key for a milestone's dispute status history
+fn get_dispute_history_key(escrow_id: u64, milestone_index: u32) -> (Symbol, u64, u32) {
+    (symbol_short!("disp_hist"), escrow_id, milestone_index)
+}
+
+// Appends a timestamped status to a milestone's dispute history
+fn record_dispute_transition(
+    env: &Env,
+    escrow_id: u64,
+    milestone_index: u32,
+    status: MilestoneStatus,
+) {
+    let key = get_dispute_history_key(escrow_id, milestone_index);
+    let mut history: Vec<(u64, MilestoneStatus)> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    history.push_back((env.ledger().timestamp(), status));
+    env.storage().persistent().set(&key, &history);
+}
+
+// Emits the outcome of a dispute resolution
+fn publish_dispute_resolved(
+    env: &Env,
+    escrow_id: u64,
+    milestone_index: u32,
+    released_to_recipient: bool,
+) {
+    #[allow(deprecated)]
+    env.events().publish(
+        (symbol_short!("resolved"), escrow_id, milestone_index),
+        released_to_recipient,
+    );
+}
+
+// Storage key for a proposed (timelocked) milestone release
+fn get_proposal_key(escrow_id: u64, milestone_index: u32) -> (Symbol, u64, u32) {
+    (symbol_short!("proposal"), escrow_id, milestone_index)
+}
+
+// Appends an escrow id to an address index, dropping the oldest id once full
+fn index_escrow(env: &Env, index_key: &(Symbol, Address), escrow_id: u64) {
+    let mut ids: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(index_key)
+        .unwrap_or(Vec::new(env));
+    if ids.len() >= MAX_INDEX_SIZE {
+        ids.pop_front();
+    }
+    ids.push_back(escrow_id);
+    env.storage().persistent().set(index_key, &ids);
+}
+
+// Removes an escrow id from an address index, if present
+fn unindex_escrow(env: &Env, index_key: &(Symbol, Address), escrow_id: u64) {
+    let mut ids: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(index_key)
+        .unwrap_or(Vec::new(env));
+    if let Some(position) = ids.first_index_of(escrow_id) {
+        ids.remove(position);
+        env.storage().persistent().set(index_key, &ids);
+    }
+}
+
+// Returns a token's decimals, caching them after the first successful read.
+// Fails with `InvalidToken` if the address is not a token contract.
+fn token_decimals(env: &Env, token: &Address) -> Result<u32, Error> {
+    let key = (symbol_short!("decimals"), token.clone());
+    if let Some(decimals) = env.storage().persistent().get(&key) {
+        return Ok(decimals);
+    }
+
+    let decimals = match token::Client::new(env, token).try_decimals() {
+        Ok(Ok(decimals)) => decimals,
+        _ => return Err(Error::InvalidToken),
+    };
+    env.storage().persistent().set(&key, &decimals);
+    Ok(decimals)
+}
+
+// Storage key for an escrow's segregated balance ledger
+fn get_balance_key(escrow_id: u64) -> (Symbol, u64) {
+    (symbol_short!("balance"), escrow_id)
+}
+
+// Returns the balance the contract holds on behalf of a single escrow
+fn read_escrow_balance(env: &Env, escrow_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&get_balance_key(escrow_id))
+        .unwrap_or(0)
+}
+
+// Records funds received on behalf of an escrow
+fn credit_escrow(env: &Env, escrow_id: u64, escrow: &Escrow, amount: i128) -> Result<(), Error> {
+    let balance = read_escrow_balance(env, escrow_id)
+        .checked_add(amount)
+        .ok_or(Error::InvalidMilestoneAmount)?;
+    env.storage()
+        .persistent()
+        .set(&get_balance_key(escrow_id), &balance);
+    adjust_depositor_locked(env, &escrow.depositor, &escrow.token, amount)?;
+    adjust_locked(env, &escrow.token, amount)
+}
+
+// Records funds leaving the contract on behalf of an escrow.
+// An escrow can never move more than its own tracked balance, so an
+// accounting bug in one escrow cannot spend funds belonging to another.
+fn debit_escrow(env: &Env, escrow_id: u64, escrow: &Escrow, amount: i128) -> Result<(), Error> {
+    let balance = read_escrow_balance(env, escrow_id);
+    if amount < 0 || amount > balance {
+        return Err(Error::BalanceMismatch);
+    }
+    env.storage()
+        .persistent()
+        .set(&get_balance_key(escrow_id), &(balance - amount));
+    adjust_depositor_locked(env, &escrow.depositor, &escrow.token, -amount)?;
+    adjust_locked(env, &escrow.token, -amount)
+}
+
+// Adds a collected fee to the fee recipient's running total
+fn record_fee(env: &Env, fee_recipient: &Address, fee: i128) -> Result<(), Error> {
+    let collected = VaultixEscrow::get_fees_collected(env.clone(), fee_recipient.clone())
+        .checked_add(fee)
+        .ok_or(Error::InvalidMilestoneAmount)?;
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("fees"), fee_recipient.clone()), &collected);
+    Ok(())
+}
+
+// Bumps a persistent entry's TTL to `(threshold, extend_to)` if the entry exists
+fn extend_if_present<K: IntoVal<Env, Val>>(env: &Env, key: &K, (threshold, extend_to): (u32, u32)) {
+    if env.storage().persistent().has(key) {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, threshold, extend_to);
+    }
+}
+
+// Persists an escrow, stamping it with the current ledger time
+fn save_escrow(env: &Env, storage_key: &(Symbol, u64), escrow: &mut Escrow) {
+    escrow.updated_at = env.ledger().timestamp();
+    env.storage().persistent().set(storage_key, escrow);
+}
+
 // Validates milestone vector and returns total amount
-fn validate_milestones(milestones: &Vec<Milestone>) -> Result<i128, Error> {
+fn validate_milestones(env: &Env, milestones: &Vec<Milestone>) -> Result<i128, Error> {
     // Check vector size to prevent gas issues
-    if milestones.len() > 20 {
+    if milestones.len() > VaultixEscrow::get_max_milestones(env.clone()) {
         return Err(Error::VectorTooLarge);
     }
 
@@ -513,19 +3178,58 @@ fn validate_milestones(milestones: &Vec<Milestone>) -> Result<i128, Error> {
             return Err(Error::ZeroAmount);
         }
 
+        // New milestones cannot claim to be partly paid already
+        if milestone.released_so_far != 0 {
+            return Err(Error::InvalidMilestoneAmount);
+        }
+
+        if milestone.deadline != 0 && milestone.deadline <= env.ledger().timestamp() {
+            return Err(Error::InvalidDeadline);
+        }
+
         total = total
             .checked_add(milestone.amount)
-            .ok_or(Error::InvalidMilestoneAmount)?;
+            .ok_or(Error::TotalOverflow)?;
+    }
+
+    // Enforce the admin-configured ceiling on the escrow total, if any
+    let max_total: Option<i128> = env.storage().instance().get(&symbol_short!("max_total"));
+    if let Some(max_total) = max_total
+        && total > max_total
+    {
+        return Err(Error::AmountTooLarge);
+    }
+
+    // Enforce the admin-configured floor on the escrow total
+    if total < VaultixEscrow::get_min_total_amount(env.clone()) {
+        return Err(Error::BelowMinimum);
+    }
+
+    // In strict mode, milestone descriptions must be unique within the escrow
+    let strict: bool = env
+        .storage()
+        .instance()
+        .get(&symbol_short!("strict"))
+        .unwrap_or(false);
+    if strict {
+        for (i, milestone) in milestones.iter().enumerate() {
+            for other in milestones.iter().skip(i + 1) {
+                if milestone.description == other.description {
+                    return Err(Error::DuplicateMilestone);
+                }
+            }
+        }
     }
 
     Ok(total)
 }
 
-// Checks if all milestones have been released
+// Checks if all milestones have been released (cancelled milestones count as settled)
 fn verify_all_released(milestones: &Vec<Milestone>) -> bool {
     for milestone in milestones.iter() {
-        if milestone.status != MilestoneStatus::Released {
-            return false;
+        match milestone.status {
+            MilestoneStatus::Released | MilestoneStatus::Cancelled | MilestoneStatus::Refunded => {}
+            _ => return false,
         }
     }
     true
@@ -1,5 +1,11 @@
 use super::*;
-use soroban_sdk::{Address, Env, testutils::Address as _, token, vec};
+extern crate std;
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, IntoVal, TryIntoVal,
+    testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke, storage::Persistent as _},
+    token, vec, xdr,
+};
+use std::rc::Rc;
 
 fn create_token_contract<'a>(
     env: &Env,
@@ -12,6 +18,46 @@ fn create_token_contract<'a>(
     )
 }
 
+// Deploys the native XLM Stellar Asset Contract
+fn create_native_token_contract<'a>(env: &Env) -> token::Client<'a> {
+    let address = env
+        .deployer()
+        .with_stellar_asset(Bytes::from_array(env, &NATIVE_ASSET_XDR))
+        .deploy();
+    token::Client::new(env, &address)
+}
+
+// Creates a classic Stellar account holding `balance` stroops of native XLM
+fn create_native_account(env: &Env, seed: u8, balance: i64) -> Address {
+    let account_id = xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(xdr::Uint256(
+        [seed; 32],
+    )));
+    let key = Rc::new(xdr::LedgerKey::Account(xdr::LedgerKeyAccount {
+        account_id: account_id.clone(),
+    }));
+    let entry = Rc::new(xdr::LedgerEntry {
+        data: xdr::LedgerEntryData::Account(xdr::AccountEntry {
+            account_id: account_id.clone(),
+            balance,
+            flags: 0,
+            home_domain: Default::default(),
+            inflation_dest: None,
+            num_sub_entries: 0,
+            seq_num: xdr::SequenceNumber(0),
+            thresholds: xdr::Thresholds([1; 4]),
+            signers: xdr::VecM::default(),
+            ext: xdr::AccountEntryExt::V0,
+        }),
+        last_modified_ledger_seq: 0,
+        ext: xdr::LedgerEntryExt::V0,
+    });
+    env.host().add_ledger_entry(&key, &entry, None).unwrap();
+
+    xdr::ScAddress::Account(account_id)
+        .try_into_val(env)
+        .unwrap()
+}
+
 #[test]
 fn test_create_and_get_escrow() {
     let env = Env::default();
@@ -36,16 +82,22 @@ fn test_create_and_get_escrow() {
             amount: 3000,
             status: MilestoneStatus::Pending,
             description: symbol_short!("Design"),
+            deadline: 0,
+            released_so_far: 0,
         },
         Milestone {
             amount: 3000,
             status: MilestoneStatus::Pending,
             description: symbol_short!("Dev"),
+            deadline: 0,
+            released_so_far: 0,
         },
         Milestone {
             amount: 4000,
             status: MilestoneStatus::Pending,
             description: symbol_short!("Deploy"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
@@ -96,11 +148,15 @@ fn test_buyer_confirm_delivery() {
             amount: 5000,
             status: MilestoneStatus::Pending,
             description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
         },
         Milestone {
             amount: 5000,
             status: MilestoneStatus::Pending,
             description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
@@ -155,11 +211,15 @@ fn test_complete_escrow() {
             amount: 5000,
             status: MilestoneStatus::Pending,
             description: symbol_short!("Task1"),
+            deadline: 0,
+            released_so_far: 0,
         },
         Milestone {
             amount: 5000,
             status: MilestoneStatus::Pending,
             description: symbol_short!("Task2"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
@@ -183,6 +243,25 @@ fn test_complete_escrow() {
     assert_eq!(escrow.total_released, 10000);
 }
 
+#[test]
+fn test_auto_complete_on_last_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, token_client, _recipient) = create_three_milestone_escrow(&env, 608);
+    client.set_auto_complete(&608, &true);
+
+    client.release_milestone(&608, &0, &token_client.address);
+    client.release_milestone(&608, &1, &token_client.address);
+    assert_eq!(client.get_escrow(&608).status, EscrowStatus::Active);
+
+    // The final release completes the escrow without calling complete_escrow
+    client.release_milestone(&608, &2, &token_client.address);
+    let escrow = client.get_escrow(&608);
+    assert_eq!(escrow.status, EscrowStatus::Completed);
+    assert_eq!(escrow.total_released, 6000);
+}
+
 #[test]
 fn test_cancel_escrow() {
     let env = Env::default();
@@ -206,6 +285,8 @@ fn test_cancel_escrow() {
             amount: 10000,
             status: MilestoneStatus::Pending,
             description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
@@ -222,6 +303,10 @@ fn test_cancel_escrow() {
 
     let escrow = client.get_escrow(&escrow_id);
     assert_eq!(escrow.status, EscrowStatus::Cancelled);
+
+    // Depositor is refunded in full when no cancellation fee is configured
+    assert_eq!(token_client.balance(&depositor), 10000);
+    assert_eq!(token_client.balance(&contract_id), 0);
 }
 
 #[test]
@@ -248,6 +333,8 @@ fn test_duplicate_escrow_id() {
             amount: 1000,
             status: MilestoneStatus::Pending,
             description: symbol_short!("Test"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
@@ -296,6 +383,8 @@ fn test_double_release() {
             amount: 1000,
             status: MilestoneStatus::Pending,
             description: symbol_short!("Task"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
@@ -338,6 +427,8 @@ fn test_too_many_milestones() {
             amount: 100,
             status: MilestoneStatus::Pending,
             description: symbol_short!("Task"),
+            deadline: 0,
+            released_so_far: 0,
         });
     }
 
@@ -351,6 +442,76 @@ fn test_too_many_milestones() {
     );
 }
 
+#[test]
+fn test_configurable_milestone_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    // Default cap still allows 20 milestones
+    assert_eq!(client.get_max_milestones(), 20);
+    let mut milestones = Vec::new(&env);
+    for _i in 0..20 {
+        milestones.push_back(Milestone {
+            amount: 100,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Task"),
+            deadline: 0,
+            released_so_far: 0,
+        });
+    }
+    client.create_escrow(
+        &70u64,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    client.set_max_milestones(&5);
+    assert_eq!(client.get_max_milestones(), 5);
+
+    let mut milestones = Vec::new(&env);
+    for _i in 0..6 {
+        milestones.push_back(Milestone {
+            amount: 100,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Task"),
+            deadline: 0,
+            released_so_far: 0,
+        });
+    }
+    let result = client.try_create_escrow(
+        &71u64,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    assert_eq!(result, Err(Ok(Error::VectorTooLarge)));
+
+    milestones.pop_back();
+    client.create_escrow(
+        &71u64,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+}
+
 #[test]
 #[should_panic(expected = "Error(Contract, #13)")]
 fn test_invalid_milestone_amount() {
@@ -375,6 +536,8 @@ fn test_invalid_milestone_amount() {
             amount: 0, // Invalid: zero amount
             status: MilestoneStatus::Pending,
             description: symbol_short!("Task"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
@@ -413,6 +576,8 @@ fn test_unauthorized_confirm_delivery() {
             amount: 1000,
             status: MilestoneStatus::Pending,
             description: symbol_short!("Task"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
@@ -452,6 +617,8 @@ fn test_double_confirm_delivery() {
             amount: 1000,
             status: MilestoneStatus::Pending,
             description: symbol_short!("Task"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
@@ -493,6 +660,8 @@ fn test_zero_amount_milestone_rejected() {
             amount: 0, // Invalid: zero amount
             status: MilestoneStatus::Pending,
             description: symbol_short!("Test"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
@@ -532,6 +701,8 @@ fn test_negative_amount_milestone_rejected() {
             amount: -1000, // Invalid: negative amount
             status: MilestoneStatus::Pending,
             description: symbol_short!("Test"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
@@ -570,6 +741,8 @@ fn test_self_dealing_rejected() {
             amount: 5000,
             status: MilestoneStatus::Pending,
             description: symbol_short!("Task"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
@@ -609,11 +782,15 @@ fn test_valid_escrow_creation_succeeds() {
             amount: 3000,
             status: MilestoneStatus::Pending,
             description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
         },
         Milestone {
             amount: 7000,
             status: MilestoneStatus::Pending,
             description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
@@ -636,107 +813,106 @@ fn test_valid_escrow_creation_succeeds() {
     assert_eq!(escrow.total_amount, 10000);
 }
 
-// ============================================================================
-// Platform Fee Tests
-// ============================================================================
-
 #[test]
-fn test_initialize_contract() {
+fn test_recipient_is_token_rejected() {
     let env = Env::default();
     env.mock_all_auths();
-
     let contract_id = env.register(VaultixEscrow, ());
     let client = VaultixEscrowClient::new(&env, &contract_id);
 
-    let treasury = Address::generate(&env);
-
-    // Initialize with default fee
-    client.initialize(&treasury, &None);
-
-    let (stored_treasury, fee_bps) = client.get_config();
-    assert_eq!(stored_treasury, treasury);
-    assert_eq!(fee_bps, 50); // Default 0.5%
-}
-
-#[test]
-fn test_initialize_with_custom_fee() {
-    let env = Env::default();
-    env.mock_all_auths();
+    let depositor = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 15u64;
 
-    let contract_id = env.register(VaultixEscrow, ());
-    let client = VaultixEscrowClient::new(&env, &contract_id);
+    // Create token contract and mint tokens
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
 
-    let treasury = Address::generate(&env);
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 5000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Task"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
 
-    // Initialize with custom fee (1%)
-    client.initialize(&treasury, &Some(100));
+    // Attempt to create escrow paying out to the token contract itself
+    let result = client.try_create_escrow(
+        &escrow_id,
+        &depositor,
+        &token_client.address,
+        &milestones,
+        &token_client.address,
+    );
 
-    let (stored_treasury, fee_bps) = client.get_config();
-    assert_eq!(stored_treasury, treasury);
-    assert_eq!(fee_bps, 100);
+    assert_eq!(result, Err(Ok(Error::InvalidRecipient)));
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #12)")]
-fn test_initialize_invalid_fee() {
+fn test_recipient_is_escrow_contract_rejected() {
     let env = Env::default();
     env.mock_all_auths();
-
     let contract_id = env.register(VaultixEscrow, ());
     let client = VaultixEscrowClient::new(&env, &contract_id);
 
-    let treasury = Address::generate(&env);
-
-    // Try to initialize with fee > 100% (should panic)
-    client.initialize(&treasury, &Some(10001));
-}
-
-#[test]
-fn test_update_fee() {
-    let env = Env::default();
-    env.mock_all_auths();
+    let depositor = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 16u64;
 
-    let contract_id = env.register(VaultixEscrow, ());
-    let client = VaultixEscrowClient::new(&env, &contract_id);
+    // Create token contract and mint tokens
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
 
-    let treasury = Address::generate(&env);
-    client.initialize(&treasury, &Some(50));
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 5000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Task"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
 
-    // Update fee to 1%
-    client.update_fee(&100);
+    // Attempt to create escrow paying out to the escrow contract
+    let result = client.try_create_escrow(
+        &escrow_id,
+        &depositor,
+        &contract_id,
+        &milestones,
+        &token_client.address,
+    );
 
-    let (_, fee_bps) = client.get_config();
-    assert_eq!(fee_bps, 100);
+    assert_eq!(result, Err(Ok(Error::InvalidRecipient)));
 }
 
 #[test]
-fn test_fee_calculation_standard_amount() {
+fn test_get_parties_addresses() {
     let env = Env::default();
     env.mock_all_auths();
-
     let contract_id = env.register(VaultixEscrow, ());
     let client = VaultixEscrowClient::new(&env, &contract_id);
 
-    // Initialize with 0.5% fee (50 bps)
-    let treasury = Address::generate(&env);
-    client.initialize(&treasury, &Some(50));
-
     let depositor = Address::generate(&env);
     let recipient = Address::generate(&env);
     let admin = Address::generate(&env);
-    let escrow_id = 100u64;
+    let escrow_id = 17u64;
 
     // Create token contract and mint tokens
     let (token_client, token_admin) = create_token_contract(&env, &admin);
     token_admin.mint(&depositor, &10000);
 
-    // Create escrow with 10000 amount
     let milestones = vec![
         &env,
         Milestone {
             amount: 10000,
             status: MilestoneStatus::Pending,
-            description: symbol_short!("Work"),
+            description: symbol_short!("Task"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
@@ -748,208 +924,327 @@ fn test_fee_calculation_standard_amount() {
         &token_client.address,
     );
 
-    // Release milestone
-    client.release_milestone(&escrow_id, &0, &token_client.address);
-
-    // Verify fee calculation: 10000 * 50 / 10000 = 50
-    let expected_fee = 50;
-    let expected_payout = 10000 - expected_fee; // 9950
-
-    assert_eq!(token_client.balance(&recipient), expected_payout);
-    assert_eq!(token_client.balance(&treasury), expected_fee);
+    let (stored_depositor, stored_recipient) = client.get_parties_addresses(&escrow_id);
+    assert_eq!(stored_depositor, depositor);
+    assert_eq!(stored_recipient, recipient);
 }
 
 #[test]
-fn test_fee_calculation_small_amount() {
+fn test_total_above_ceiling_rejected() {
     let env = Env::default();
     env.mock_all_auths();
-
     let contract_id = env.register(VaultixEscrow, ());
     let client = VaultixEscrowClient::new(&env, &contract_id);
 
-    // Initialize with 0.5% fee (50 bps)
     let treasury = Address::generate(&env);
-    client.initialize(&treasury, &Some(50));
+    client.initialize(&treasury, &None);
+    client.set_max_total_amount(&10000);
 
     let depositor = Address::generate(&env);
     let recipient = Address::generate(&env);
     let admin = Address::generate(&env);
-    let escrow_id = 101u64;
 
     // Create token contract and mint tokens
     let (token_client, token_admin) = create_token_contract(&env, &admin);
-    token_admin.mint(&depositor, &10000);
+    token_admin.mint(&depositor, &20000);
 
-    // Create escrow with small amount (100)
     let milestones = vec![
         &env,
         Milestone {
-            amount: 100,
+            amount: 6000,
             status: MilestoneStatus::Pending,
-            description: symbol_short!("Small"),
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 5000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
-    client.create_escrow(
-        &escrow_id,
+    // 11000 exceeds the 10000 ceiling
+    let result = client.try_create_escrow(
+        &18,
         &depositor,
         &recipient,
         &milestones,
         &token_client.address,
     );
+    assert_eq!(result, Err(Ok(Error::AmountTooLarge)));
 
-    // Release milestone
-    client.release_milestone(&escrow_id, &0, &token_client.address);
-
-    // Verify fee calculation: 100 * 50 / 10000 = 0 (rounds down)
-    let expected_fee = 0;
-    let expected_payout = 100 - expected_fee; // 100
-
-    assert_eq!(token_client.balance(&recipient), expected_payout);
-    assert_eq!(token_client.balance(&treasury), expected_fee);
+    // Exactly at the ceiling is accepted
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    let result = client.try_create_escrow(
+        &19,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    assert!(result.is_ok());
 }
 
 #[test]
-fn test_fee_calculation_large_amount() {
+fn test_total_overflow_rejected() {
     let env = Env::default();
     env.mock_all_auths();
-
     let contract_id = env.register(VaultixEscrow, ());
     let client = VaultixEscrowClient::new(&env, &contract_id);
 
-    // Initialize with 1% fee (100 bps)
-    let treasury = Address::generate(&env);
-    client.initialize(&treasury, &Some(100));
-
     let depositor = Address::generate(&env);
     let recipient = Address::generate(&env);
     let admin = Address::generate(&env);
-    let escrow_id = 102u64;
 
-    // Create token contract and mint tokens
-    let (token_client, token_admin) = create_token_contract(&env, &admin);
-    token_admin.mint(&depositor, &1_000_000);
+    let (token_client, _token_admin) = create_token_contract(&env, &admin);
 
-    // Create escrow with large amount
     let milestones = vec![
         &env,
         Milestone {
-            amount: 1_000_000,
+            amount: i128::MAX,
             status: MilestoneStatus::Pending,
-            description: symbol_short!("Large"),
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 1,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
-    client.create_escrow(
-        &escrow_id,
+    let result = client.try_create_escrow(
+        &22,
         &depositor,
         &recipient,
         &milestones,
         &token_client.address,
     );
-
-    // Release milestone
-    client.release_milestone(&escrow_id, &0, &token_client.address);
-
-    // Verify fee calculation: 1000000 * 100 / 10000 = 10000
-    let expected_fee = 10_000;
-    let expected_payout = 1_000_000 - expected_fee; // 990000
-
-    assert_eq!(token_client.balance(&recipient), expected_payout);
-    assert_eq!(token_client.balance(&treasury), expected_fee);
+    assert_eq!(result, Err(Ok(Error::TotalOverflow)));
 }
 
 #[test]
-fn test_fee_calculation_boundary_value() {
+fn test_total_below_minimum_rejected() {
     let env = Env::default();
     env.mock_all_auths();
-
     let contract_id = env.register(VaultixEscrow, ());
     let client = VaultixEscrowClient::new(&env, &contract_id);
 
-    // Initialize with 0.5% fee (50 bps)
     let treasury = Address::generate(&env);
-    client.initialize(&treasury, &Some(50));
+    client.initialize(&treasury, &None);
+    assert_eq!(client.get_min_total_amount(), 1);
+    client.set_min_total_amount(&100);
 
     let depositor = Address::generate(&env);
     let recipient = Address::generate(&env);
     let admin = Address::generate(&env);
-    let escrow_id = 103u64;
 
     // Create token contract and mint tokens
     let (token_client, token_admin) = create_token_contract(&env, &admin);
-    token_admin.mint(&depositor, &10000);
+    token_admin.mint(&depositor, &1000);
 
-    // Create escrow with boundary amount (200 - minimum for 1 unit fee)
     let milestones = vec![
         &env,
         Milestone {
-            amount: 200,
+            amount: 20,
             status: MilestoneStatus::Pending,
-            description: symbol_short!("Boundary"),
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 30,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
-    client.create_escrow(
-        &escrow_id,
+    // 50 is below the 100 minimum
+    let result = client.try_create_escrow(
+        &20,
         &depositor,
         &recipient,
         &milestones,
         &token_client.address,
     );
+    assert_eq!(result, Err(Ok(Error::BelowMinimum)));
 
-    // Release milestone
-    client.release_milestone(&escrow_id, &0, &token_client.address);
-
-    // Verify fee calculation: 200 * 50 / 10000 = 1
-    let expected_fee = 1;
-    let expected_payout = 200 - expected_fee; // 199
-
-    assert_eq!(token_client.balance(&recipient), expected_payout);
-    assert_eq!(token_client.balance(&treasury), expected_fee);
+    // Exactly at the minimum is accepted
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 100,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    let result = client.try_create_escrow(
+        &21,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    assert!(result.is_ok());
 }
 
 #[test]
-fn test_multiple_milestone_releases_accumulate_fees() {
+fn test_milestone_minimum_scales_with_decimals() {
     let env = Env::default();
     env.mock_all_auths();
-
     let contract_id = env.register(VaultixEscrow, ());
     let client = VaultixEscrowClient::new(&env, &contract_id);
 
-    // Initialize with 0.5% fee (50 bps)
     let treasury = Address::generate(&env);
-    client.initialize(&treasury, &Some(50));
+    client.initialize(&treasury, &None);
+    client.set_min_milestone_units(&1);
 
     let depositor = Address::generate(&env);
     let recipient = Address::generate(&env);
     let admin = Address::generate(&env);
-    let escrow_id = 104u64;
 
-    // Create token contract and mint tokens
+    // Stellar Asset Contracts use 7 decimals, so the minimum is 10^7
     let (token_client, token_admin) = create_token_contract(&env, &admin);
-    token_admin.mint(&depositor, &10000);
+    assert_eq!(token_client.decimals(), 7);
+    token_admin.mint(&depositor, &100_000_000);
 
-    // Create escrow with multiple milestones
     let milestones = vec![
         &env,
         Milestone {
-            amount: 5000,
+            amount: 50_000_000,
             status: MilestoneStatus::Pending,
-            description: symbol_short!("M1"),
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
         },
         Milestone {
-            amount: 3000,
+            amount: 9_999_999,
             status: MilestoneStatus::Pending,
-            description: symbol_short!("M2"),
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
         },
+    ];
+    let result = client.try_create_escrow(
+        &23,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    assert_eq!(result, Err(Ok(Error::BelowMinimum)));
+
+    let milestones = vec![
+        &env,
         Milestone {
-            amount: 2000,
+            amount: 50_000_000,
             status: MilestoneStatus::Pending,
-            description: symbol_short!("M3"),
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 10_000_000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    let result = client.try_create_escrow(
+        &23,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_invalid_token_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 5000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Task"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
+    // Address with no contract deployed
+    let missing_token = Address::generate(&env);
+    let result = client.try_create_escrow(&20, &depositor, &recipient, &milestones, &missing_token);
+    assert_eq!(result, Err(Ok(Error::InvalidToken)));
+
+    // Deployed contract that is not a token
+    let not_a_token = env.register(MockSwap, ());
+    let result = client.try_create_escrow(&21, &depositor, &recipient, &milestones, &not_a_token);
+    assert_eq!(result, Err(Ok(Error::InvalidToken)));
+}
+
+#[test]
+fn test_create_escrow_emits_created_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 1800u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 6000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
     client.create_escrow(
         &escrow_id,
         &depositor,
@@ -958,49 +1253,128 @@ fn test_multiple_milestone_releases_accumulate_fees() {
         &token_client.address,
     );
 
-    // Release first milestone: 5000 * 50 / 10000 = 25 fee
-    client.release_milestone(&escrow_id, &0, &token_client.address);
-    assert_eq!(token_client.balance(&recipient), 4975);
-    assert_eq!(token_client.balance(&treasury), 25);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (symbol_short!("created"), escrow_id).into_val(&env),
+                (
+                    depositor.clone(),
+                    recipient.clone(),
+                    token_client.address.clone(),
+                    10000i128
+                )
+                    .into_val(&env),
+            ),
+        ]
+    );
+}
 
-    // Release second milestone: 3000 * 50 / 10000 = 15 fee
-    client.release_milestone(&escrow_id, &1, &token_client.address);
-    assert_eq!(token_client.balance(&recipient), 4975 + 2985);
-    assert_eq!(token_client.balance(&treasury), 25 + 15);
+// ============================================================================
+// Platform Fee Tests
+// ============================================================================
 
-    // Release third milestone: 2000 * 50 / 10000 = 10 fee
-    client.release_milestone(&escrow_id, &2, &token_client.address);
-    assert_eq!(token_client.balance(&recipient), 4975 + 2985 + 1990);
-    assert_eq!(token_client.balance(&treasury), 25 + 15 + 10);
+#[test]
+fn test_initialize_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+
+    // Initialize with default fee
+    client.initialize(&treasury, &None);
+
+    let (stored_treasury, fee_bps) = client.get_config();
+    assert_eq!(stored_treasury, treasury);
+    assert_eq!(fee_bps, 50); // Default 0.5%
 }
 
 #[test]
-fn test_zero_fee_configuration() {
+fn test_initialize_with_custom_fee() {
     let env = Env::default();
     env.mock_all_auths();
 
     let contract_id = env.register(VaultixEscrow, ());
     let client = VaultixEscrowClient::new(&env, &contract_id);
 
-    // Initialize with 0% fee
     let treasury = Address::generate(&env);
-    client.initialize(&treasury, &Some(0));
+
+    // Initialize with custom fee (1%)
+    client.initialize(&treasury, &Some(100));
+
+    let (stored_treasury, fee_bps) = client.get_config();
+    assert_eq!(stored_treasury, treasury);
+    assert_eq!(fee_bps, 100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+fn test_initialize_invalid_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+
+    // Try to initialize with fee > 100% (should panic)
+    client.initialize(&treasury, &Some(10001));
+}
+
+#[test]
+fn test_update_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(50));
+
+    // Update fee to 1%
+    client.update_fee(&100);
+
+    let (_, fee_bps) = client.get_config();
+    assert_eq!(fee_bps, 100);
+}
+
+#[test]
+fn test_fee_calculation_standard_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    // Initialize with 0.5% fee (50 bps)
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(50));
 
     let depositor = Address::generate(&env);
     let recipient = Address::generate(&env);
     let admin = Address::generate(&env);
-    let escrow_id = 105u64;
+    let escrow_id = 100u64;
 
     // Create token contract and mint tokens
     let (token_client, token_admin) = create_token_contract(&env, &admin);
     token_admin.mint(&depositor, &10000);
 
+    // Create escrow with 10000 amount
     let milestones = vec![
         &env,
         Milestone {
             amount: 10000,
             status: MilestoneStatus::Pending,
-            description: symbol_short!("NoFee"),
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
@@ -1015,39 +1389,47 @@ fn test_zero_fee_configuration() {
     // Release milestone
     client.release_milestone(&escrow_id, &0, &token_client.address);
 
-    // Verify no fee collected
-    assert_eq!(token_client.balance(&recipient), 10000);
-    assert_eq!(token_client.balance(&treasury), 0);
+    // Verify fee calculation: 10000 * 50 / 10000 = 50
+    let expected_fee = 50;
+    let expected_payout = 10000 - expected_fee; // 9950
+
+    assert_eq!(token_client.balance(&recipient), expected_payout);
+    assert_eq!(token_client.balance(&treasury), expected_fee);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #11)")]
-fn test_release_without_initialization() {
+fn test_fee_calculation_small_amount() {
     let env = Env::default();
     env.mock_all_auths();
 
     let contract_id = env.register(VaultixEscrow, ());
     let client = VaultixEscrowClient::new(&env, &contract_id);
 
+    // Initialize with 0.5% fee (50 bps)
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(50));
+
     let depositor = Address::generate(&env);
     let recipient = Address::generate(&env);
     let admin = Address::generate(&env);
-    let escrow_id = 106u64;
+    let escrow_id = 101u64;
 
     // Create token contract and mint tokens
     let (token_client, token_admin) = create_token_contract(&env, &admin);
     token_admin.mint(&depositor, &10000);
 
+    // Create escrow with small amount (100)
     let milestones = vec![
         &env,
         Milestone {
-            amount: 1000,
+            amount: 100,
             status: MilestoneStatus::Pending,
-            description: symbol_short!("Test"),
+            description: symbol_short!("Small"),
+            deadline: 0,
+            released_so_far: 0,
         },
     ];
 
-    // Create escrow without initializing contract
     client.create_escrow(
         &escrow_id,
         &depositor,
@@ -1056,6 +1438,4526 @@ fn test_release_without_initialization() {
         &token_client.address,
     );
 
-    // This should panic with Error #11 (TreasuryNotInitialized)
+    // Release milestone
     client.release_milestone(&escrow_id, &0, &token_client.address);
+
+    // Verify fee calculation: 100 * 50 / 10000 = 0 (rounds down)
+    let expected_fee = 0;
+    let expected_payout = 100 - expected_fee; // 100
+
+    assert_eq!(token_client.balance(&recipient), expected_payout);
+    assert_eq!(token_client.balance(&treasury), expected_fee);
+}
+
+#[test]
+fn test_fee_calculation_large_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    // Initialize with 1% fee (100 bps)
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(100));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 102u64;
+
+    // Create token contract and mint tokens
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &1_000_000);
+
+    // Create escrow with large amount
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 1_000_000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Large"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    // Release milestone
+    client.release_milestone(&escrow_id, &0, &token_client.address);
+
+    // Verify fee calculation: 1000000 * 100 / 10000 = 10000
+    let expected_fee = 10_000;
+    let expected_payout = 1_000_000 - expected_fee; // 990000
+
+    assert_eq!(token_client.balance(&recipient), expected_payout);
+    assert_eq!(token_client.balance(&treasury), expected_fee);
+}
+
+#[test]
+fn test_fee_calculation_boundary_value() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    // Initialize with 0.5% fee (50 bps)
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(50));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 103u64;
+
+    // Create token contract and mint tokens
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    // Create escrow with boundary amount (200 - minimum for 1 unit fee)
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 200,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Boundary"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    // Release milestone
+    client.release_milestone(&escrow_id, &0, &token_client.address);
+
+    // Verify fee calculation: 200 * 50 / 10000 = 1
+    let expected_fee = 1;
+    let expected_payout = 200 - expected_fee; // 199
+
+    assert_eq!(token_client.balance(&recipient), expected_payout);
+    assert_eq!(token_client.balance(&treasury), expected_fee);
+}
+
+#[test]
+fn test_multiple_milestone_releases_accumulate_fees() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    // Initialize with 0.5% fee (50 bps)
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(50));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 104u64;
+
+    // Create token contract and mint tokens
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    // Create escrow with multiple milestones
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 5000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("M1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 3000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("M2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 2000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("M3"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    // Release first milestone: 5000 * 50 / 10000 = 25 fee
+    client.release_milestone(&escrow_id, &0, &token_client.address);
+    assert_eq!(token_client.balance(&recipient), 4975);
+    assert_eq!(token_client.balance(&treasury), 25);
+
+    // Release second milestone: 3000 * 50 / 10000 = 15 fee
+    client.release_milestone(&escrow_id, &1, &token_client.address);
+    assert_eq!(token_client.balance(&recipient), 4975 + 2985);
+    assert_eq!(token_client.balance(&treasury), 25 + 15);
+
+    // Release third milestone: 2000 * 50 / 10000 = 10 fee
+    client.release_milestone(&escrow_id, &2, &token_client.address);
+    assert_eq!(token_client.balance(&recipient), 4975 + 2985 + 1990);
+    assert_eq!(token_client.balance(&treasury), 25 + 15 + 10);
+}
+
+#[test]
+fn test_fees_collected_accumulator() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    // Initialize with 1% fee (100 bps)
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(100));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &20000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 3000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 7000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &110,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    client.create_escrow(
+        &111,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    assert_eq!(client.get_fees_collected(&treasury), 0);
+
+    // Fees from releases across escrows: 30 + 70 + 30
+    client.release_milestone(&110, &0, &token_client.address);
+    client.release_milestone(&110, &1, &token_client.address);
+    client.release_milestone(&111, &0, &token_client.address);
+
+    assert_eq!(client.get_fees_collected(&treasury), 130);
+    assert_eq!(token_client.balance(&treasury), 130);
+}
+
+#[test]
+fn test_zero_fee_configuration() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    // Initialize with 0% fee
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 105u64;
+
+    // Create token contract and mint tokens
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("NoFee"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    // Release milestone
+    client.release_milestone(&escrow_id, &0, &token_client.address);
+
+    // Verify no fee collected
+    assert_eq!(token_client.balance(&recipient), 10000);
+    assert_eq!(token_client.balance(&treasury), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+fn test_release_without_initialization() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 106u64;
+
+    // Create token contract and mint tokens
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 1000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Test"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    // Create escrow without initializing contract
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    // This should panic with Error #11 (TreasuryNotInitialized)
+    client.release_milestone(&escrow_id, &0, &token_client.address);
+}
+
+// ============================================================================
+// Swap Payout Tests
+// ============================================================================
+
+// Mock swap contract paying out 1:1 in `token_out` from its own reserve
+#[contract]
+pub struct MockSwap;
+
+#[contractimpl]
+impl MockSwap {
+    pub fn swap(
+        env: Env,
+        _token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        to: Address,
+    ) -> i128 {
+        let token_client = token::Client::new(&env, &token_out);
+        token_client.transfer(&env.current_contract_address(), &to, &amount_in);
+        amount_in
+    }
+}
+
+// Mock swap contract that keeps the input and reports success without paying out
+#[contract]
+pub struct MockPocketSwap;
+
+#[contractimpl]
+impl MockPocketSwap {
+    pub fn swap(
+        _env: Env,
+        _token_in: Address,
+        _token_out: Address,
+        amount_in: i128,
+        _to: Address,
+    ) -> i128 {
+        amount_in
+    }
+}
+
+#[test]
+fn test_release_with_payout_swap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    // Initialize with 0.5% fee (50 bps)
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(50));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 200u64;
+
+    // Escrowed token and the token the recipient wants to be paid in
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    let (payout_client, payout_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    // Fund the swap contract's payout reserve
+    let swap_id = env.register(MockSwap, ());
+    payout_admin.mint(&swap_id, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    client.set_payout_swap(&escrow_id, &payout_client.address, &swap_id);
+
+    client.release_milestone(&escrow_id, &0, &token_client.address);
+
+    // Recipient is paid in the payout token, net of the platform fee
+    assert_eq!(payout_client.balance(&recipient), 9950);
+    assert_eq!(token_client.balance(&recipient), 0);
+    assert_eq!(token_client.balance(&swap_id), 9950);
+    assert_eq!(token_client.balance(&treasury), 50);
+}
+
+#[test]
+fn test_release_reverts_on_swap_failure() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(50));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 201u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    let (payout_client, _) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    // Swap contract has no payout reserve, so the swap fails
+    let swap_id = env.register(MockSwap, ());
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    client.set_payout_swap(&escrow_id, &payout_client.address, &swap_id);
+
+    let result = client.try_release_milestone(&escrow_id, &0, &token_client.address);
+    assert_eq!(result, Err(Ok(Error::SwapFailed)));
+
+    // Nothing moved and the milestone is still pending
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.total_released, 0);
+    assert_eq!(
+        escrow.milestones.get(0).unwrap().status,
+        MilestoneStatus::Pending
+    );
+    assert_eq!(token_client.balance(&contract_id), 10000);
+    assert_eq!(token_client.balance(&swap_id), 0);
+}
+
+#[test]
+fn test_release_reverts_when_swap_pockets_funds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 202u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    let (payout_client, _) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    // Swap contract reports success but never pays the recipient
+    let swap_id = env.register(MockPocketSwap, ());
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    client.set_payout_swap(&escrow_id, &payout_client.address, &swap_id);
+
+    let result = client.try_release_milestone(&escrow_id, &0, &token_client.address);
+    assert_eq!(result, Err(Ok(Error::SwapFailed)));
+
+    assert_eq!(payout_client.balance(&recipient), 0);
+    assert_eq!(token_client.balance(&swap_id), 0);
+    assert_eq!(token_client.balance(&contract_id), 10000);
+    assert_eq!(client.get_escrow(&escrow_id).total_released, 0);
+}
+
+// ============================================================================
+// Storage TTL Tests
+// ============================================================================
+
+#[test]
+fn test_extend_escrow_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 300u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    // Let the escrow sit dormant until it is close to expiry
+    let ttl_before = env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&get_storage_key(escrow_id))
+    });
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + ttl_before - 50);
+
+    client.extend_escrow_ttl(&escrow_id);
+
+    let (ttl_after, balance_ttl_after) = env.as_contract(&contract_id, || {
+        (
+            env.storage()
+                .persistent()
+                .get_ttl(&get_storage_key(escrow_id)),
+            env.storage()
+                .persistent()
+                .get_ttl(&get_balance_key(escrow_id)),
+        )
+    });
+    assert_eq!(ttl_after, 2_000_000);
+
+    // The escrow's spendable balance must not expire before the escrow does
+    assert_eq!(balance_ttl_after, 2_000_000);
+}
+
+#[test]
+fn test_extend_escrow_ttl_uses_configured_values() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &None);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 301u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    assert_eq!(client.get_ttl_config(), (100, 2_000_000));
+    assert_eq!(
+        client.try_set_ttl_config(&500, &400),
+        Err(Ok(Error::InvalidTtlConfig))
+    );
+    client.set_ttl_config(&500, &100_000);
+    assert_eq!(client.get_ttl_config(), (500, 100_000));
+
+    // Within the custom threshold, but outside the default one
+    let ttl_before = env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&get_storage_key(escrow_id))
+    });
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + ttl_before - 400);
+
+    client.extend_escrow_ttl(&escrow_id);
+
+    let ttl_after = env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&get_storage_key(escrow_id))
+    });
+    assert_eq!(ttl_after, 100_000);
+}
+
+#[test]
+fn test_extend_escrow_ttl_missing_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let result = client.try_extend_escrow_ttl(&999);
+    assert_eq!(result, Err(Ok(Error::EscrowNotFound)));
+}
+
+// ============================================================================
+// Milestone Cancellation Tests
+// ============================================================================
+
+#[test]
+fn test_cancel_single_milestone_refunds_depositor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 400u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 3000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Design"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 3000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Dev"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Deploy"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    // Drop the second milestone only
+    client.cancel_milestones(&escrow_id, &vec![&env, 1u32]);
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Active);
+    assert_eq!(escrow.total_amount, 7000);
+    assert_eq!(
+        escrow.milestones.get(0).unwrap().status,
+        MilestoneStatus::Pending
+    );
+    assert_eq!(
+        escrow.milestones.get(1).unwrap().status,
+        MilestoneStatus::Cancelled
+    );
+    assert_eq!(
+        escrow.milestones.get(2).unwrap().status,
+        MilestoneStatus::Pending
+    );
+
+    // Only the cancelled milestone's amount is refunded
+    assert_eq!(token_client.balance(&depositor), 3000);
+    assert_eq!(token_client.balance(&contract_id), 7000);
+
+    // A cancelled milestone can no longer be released
+    let result = client.try_confirm_delivery(&escrow_id, &1, &depositor);
+    assert_eq!(result, Err(Ok(Error::MilestoneNotPending)));
+}
+
+#[test]
+fn test_cancel_released_milestone_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 401u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 5000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 5000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    client.confirm_delivery(&escrow_id, &0, &depositor);
+
+    // Cancelling a released milestone reverts the whole call
+    let result = client.try_cancel_milestones(&escrow_id, &vec![&env, 1u32, 0u32]);
+    assert_eq!(result, Err(Ok(Error::MilestoneAlreadyReleased)));
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.total_amount, 10000);
+    assert_eq!(
+        escrow.milestones.get(1).unwrap().status,
+        MilestoneStatus::Pending
+    );
+}
+
+#[test]
+fn test_all_released_treats_cancelled_as_settled() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 402u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 5000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 5000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    assert!(!client.all_released(&escrow_id));
+
+    // One released, one still pending
+    client.confirm_delivery(&escrow_id, &0, &depositor);
+    assert!(!client.all_released(&escrow_id));
+
+    // Cancelling the last pending milestone settles the escrow
+    client.cancel_milestones(&escrow_id, &vec![&env, 1u32]);
+    assert!(client.all_released(&escrow_id));
+
+    client.complete_escrow(&escrow_id);
+    assert_eq!(client.get_state(&escrow_id), EscrowStatus::Completed);
+}
+
+// ============================================================================
+// Cancellation Fee Tests
+// ============================================================================
+
+#[test]
+fn test_cancel_escrow_deducts_cancel_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    // Initialize and charge 2% on cancellation
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(50));
+    client.update_cancel_fee(&200);
+    assert_eq!(client.get_cancel_fee(), 200);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 500u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    client.cancel_escrow(&escrow_id);
+
+    // Refund reduced by 10000 * 200 / 10000 = 200
+    assert_eq!(token_client.balance(&depositor), 9800);
+    assert_eq!(token_client.balance(&treasury), 200);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_cancel_fee_above_cap_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &None);
+
+    let result = client.try_update_cancel_fee(&1001);
+    assert_eq!(result, Err(Ok(Error::InvalidFeeConfiguration)));
+}
+
+#[test]
+fn test_cancel_fee_skipped_when_nothing_to_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &None);
+    client.update_cancel_fee(&1000);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 501u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    // Dropping the only milestone already refunds everything
+    client.cancel_milestones(&escrow_id, &vec![&env, 0u32]);
+    client.cancel_escrow(&escrow_id);
+
+    assert_eq!(client.get_state(&escrow_id), EscrowStatus::Cancelled);
+    assert_eq!(token_client.balance(&depositor), 10000);
+    assert_eq!(token_client.balance(&treasury), 0);
+}
+
+// ============================================================================
+// Query Tests
+// ============================================================================
+
+#[test]
+fn test_get_released_indices() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 600u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 3000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Design"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 3000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Dev"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Deploy"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    assert_eq!(client.get_released_indices(&escrow_id), vec![&env]);
+
+    client.confirm_delivery(&escrow_id, &2, &depositor);
+    client.confirm_delivery(&escrow_id, &0, &depositor);
+
+    assert_eq!(
+        client.get_released_indices(&escrow_id),
+        vec![&env, 0u32, 2u32]
+    );
+}
+
+#[test]
+fn test_get_milestones_by_status() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 606u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let design = Milestone {
+        amount: 3000,
+        status: MilestoneStatus::Pending,
+        description: symbol_short!("Design"),
+        deadline: 0,
+        released_so_far: 0,
+    };
+    let dev = Milestone {
+        amount: 3000,
+        status: MilestoneStatus::Pending,
+        description: symbol_short!("Dev"),
+        deadline: 0,
+        released_so_far: 0,
+    };
+    let deploy = Milestone {
+        amount: 4000,
+        status: MilestoneStatus::Pending,
+        description: symbol_short!("Deploy"),
+        deadline: 0,
+        released_so_far: 0,
+    };
+    let milestones = vec![&env, design.clone(), dev.clone(), deploy.clone()];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    client.confirm_delivery(&escrow_id, &1, &depositor);
+
+    let pending = client.get_milestones_by_status(&escrow_id, &MilestoneStatus::Pending);
+    assert_eq!(pending.len(), 2);
+    let (index, milestone) = pending.get(0).unwrap();
+    assert_eq!(index, 0);
+    assert_eq!(milestone.description, design.description);
+    let (index, milestone) = pending.get(1).unwrap();
+    assert_eq!(index, 2);
+    assert_eq!(milestone.amount, deploy.amount);
+
+    let released = client.get_milestones_by_status(&escrow_id, &MilestoneStatus::Released);
+    assert_eq!(released.len(), 1);
+    assert_eq!(released.get(0).unwrap().0, 1);
+
+    let disputed = client.get_milestones_by_status(&escrow_id, &MilestoneStatus::Disputed);
+    assert_eq!(disputed.len(), 0);
+}
+
+#[test]
+fn test_get_milestone() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, token_client, _recipient) = create_three_milestone_escrow(&env, 607);
+
+    let milestone = client.get_milestone(&607, &1);
+    assert_eq!(milestone.amount, 2000);
+    assert_eq!(milestone.status, MilestoneStatus::Pending);
+
+    client.release_milestone(&607, &1, &token_client.address);
+    assert_eq!(
+        client.get_milestone(&607, &1).status,
+        MilestoneStatus::Released
+    );
+
+    assert_eq!(
+        client.try_get_milestone(&607, &3).err(),
+        Some(Ok(Error::MilestoneNotFound))
+    );
+}
+
+#[test]
+fn test_get_milestone_statuses() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 602u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &3000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 1000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Design"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 1000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Build"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 1000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Deploy"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    client.confirm_delivery(&escrow_id, &0, &depositor);
+
+    assert_eq!(
+        client.get_milestone_statuses(&escrow_id),
+        vec![
+            &env,
+            MilestoneStatus::Released,
+            MilestoneStatus::Pending,
+            MilestoneStatus::Pending
+        ]
+    );
+}
+
+#[test]
+fn test_get_next_releasable_milestone() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 604u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &2000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 1000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Design"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 1000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Build"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    assert_eq!(client.get_next_releasable_milestone(&escrow_id), Some(0));
+
+    client.confirm_delivery(&escrow_id, &0, &depositor);
+    assert_eq!(client.get_next_releasable_milestone(&escrow_id), Some(1));
+
+    client.confirm_delivery(&escrow_id, &1, &depositor);
+    assert_eq!(client.get_next_releasable_milestone(&escrow_id), None);
+}
+
+#[test]
+fn test_get_value_progress() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 601u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    // One large milestone among small ones
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 500,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Kickoff"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 9000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Build"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 500,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Handoff"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    assert_eq!(client.get_value_progress(&escrow_id), (0, 10000));
+
+    // One of three milestones by count, but 90% by value
+    client.confirm_delivery(&escrow_id, &1, &depositor);
+    assert_eq!(client.get_value_progress(&escrow_id), (9000, 10000));
+}
+
+#[test]
+fn test_get_remaining_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 603u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 3500,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 6500,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    assert_eq!(client.get_remaining_amount(&escrow_id), 10000);
+
+    client.confirm_delivery(&escrow_id, &0, &depositor);
+    assert_eq!(client.get_remaining_amount(&escrow_id), 6500);
+
+    assert_eq!(
+        client.try_get_remaining_amount(&999),
+        Err(Ok(Error::EscrowNotFound))
+    );
+}
+
+#[test]
+fn test_get_creator() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 602u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    assert_eq!(client.get_creator(&escrow_id), depositor);
+    assert_eq!(client.get_escrow(&escrow_id).creator, depositor);
+}
+
+// ============================================================================
+// Strict Milestone Validation Tests
+// ============================================================================
+
+#[test]
+fn test_duplicate_descriptions_allowed_by_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 700u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 5000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Task"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 5000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Task"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    let result = client.try_create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_duplicate_descriptions_rejected_in_strict_mode() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &None);
+    client.set_strict_milestones(&true);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 701u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 3000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Task"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 3000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Review"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Task"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    let result = client.try_create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    assert_eq!(result, Err(Ok(Error::DuplicateMilestone)));
+}
+
+#[test]
+fn test_get_last_updated_tracks_ledger_time() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &None);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 603u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 5000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 5000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    env.ledger().set_timestamp(1_000);
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    assert_eq!(client.get_last_updated(&escrow_id), 1_000);
+
+    env.ledger().set_timestamp(5_000);
+    client.release_milestone(&escrow_id, &0, &token_client.address);
+    assert_eq!(client.get_last_updated(&escrow_id), 5_000);
+}
+
+// ============================================================================
+// Native XLM Tests
+// ============================================================================
+
+#[test]
+fn test_create_xlm_escrow_and_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    // Initialize with 0.5% fee (50 bps)
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(50));
+
+    let xlm = create_native_token_contract(&env);
+    let depositor = create_native_account(&env, 1, 100_000_000);
+    let recipient = Address::generate(&env);
+    let escrow_id = 800u64;
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10_000_000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_xlm_escrow(&escrow_id, &depositor, &recipient, &milestones);
+
+    // The native asset contract is resolved as the escrow token
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.token, xlm.address);
+    assert_eq!(xlm.balance(&depositor), 90_000_000);
+    assert_eq!(xlm.balance(&contract_id), 10_000_000);
+
+    client.release_milestone(&escrow_id, &0, &xlm.address);
+
+    assert_eq!(xlm.balance(&recipient), 9_950_000);
+    assert_eq!(xlm.balance(&treasury), 50_000);
+    assert_eq!(xlm.balance(&contract_id), 0);
+}
+
+// ============================================================================
+// Monitoring Tests
+// ============================================================================
+
+#[test]
+fn test_escrow_discrepancy_zero_when_healthy() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(50));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &20000);
+
+    // Two escrows sharing the same token pool
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 6000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &900,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    client.create_escrow(
+        &901,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    assert_eq!(client.get_escrow_discrepancy(&900), 0);
+
+    client.release_milestone(&900, &0, &token_client.address);
+    assert_eq!(client.get_escrow_discrepancy(&900), 0);
+    assert_eq!(client.get_escrow_discrepancy(&901), 0);
+
+    client.cancel_escrow(&901);
+    assert_eq!(client.get_escrow_discrepancy(&900), 0);
+    assert_eq!(client.get_escrow_discrepancy(&901), 0);
+}
+
+#[test]
+fn test_escrow_discrepancy_reports_shortfall() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 902u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    // Simulate funds leaving the pool outside of escrow accounting
+    let drain = Address::generate(&env);
+    token_client.transfer(&contract_id, &drain, &2500);
+
+    assert_eq!(client.get_escrow_discrepancy(&escrow_id), 2500);
+}
+
+// ============================================================================
+// Segregated Balance Tests
+// ============================================================================
+
+#[test]
+fn test_escrow_balances_are_segregated() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(50));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &30000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 6000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    for escrow_id in [1000u64, 1001, 1002] {
+        client.create_escrow(
+            &escrow_id,
+            &depositor,
+            &recipient,
+            &milestones,
+            &token_client.address,
+        );
+        assert_eq!(client.get_escrow_balance(&escrow_id), 10000);
+    }
+
+    // Operations on one escrow only ever touch that escrow's balance
+    client.release_milestone(&1000, &0, &token_client.address);
+    client.cancel_escrow(&1001);
+
+    assert_eq!(client.get_escrow_balance(&1000), 6000);
+    assert_eq!(client.get_escrow_balance(&1001), 0);
+    assert_eq!(client.get_escrow_balance(&1002), 10000);
+    assert_eq!(token_client.balance(&contract_id), 16000);
+}
+
+#[test]
+fn test_release_in_foreign_token_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let attacker = Address::generate(&env);
+    let accomplice = Address::generate(&env);
+    let victim = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+
+    let (cheap_token, cheap_admin) = create_token_contract(&env, &admin);
+    let (valuable_token, valuable_admin) = create_token_contract(&env, &admin);
+    cheap_admin.mint(&attacker, &1000);
+    valuable_admin.mint(&victim, &1000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 1000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &1003,
+        &attacker,
+        &accomplice,
+        &milestones,
+        &cheap_token.address,
+    );
+    client.create_escrow(
+        &1004,
+        &victim,
+        &recipient,
+        &milestones,
+        &valuable_token.address,
+    );
+
+    // Paying out an escrow in another escrow's token is refused
+    let result = client.try_release_milestone(&1003, &0, &valuable_token.address);
+    assert_eq!(result, Err(Ok(Error::InvalidToken)));
+    assert_eq!(valuable_token.balance(&accomplice), 0);
+    assert_eq!(valuable_token.balance(&contract_id), 1000);
+    assert_eq!(client.get_escrow_balance(&1004), 1000);
+
+    client.release_milestone(&1003, &0, &cheap_token.address);
+    assert_eq!(cheap_token.balance(&accomplice), 1000);
+    assert_eq!(valuable_token.balance(&contract_id), 1000);
+}
+
+#[test]
+fn test_release_beyond_escrow_balance_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(50));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &20000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &1003,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    client.create_escrow(
+        &1004,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    // Simulate an accounting bug that shrinks escrow 1003's tracked balance
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&get_balance_key(1003), &5000i128);
+    });
+
+    // The pool still holds enough tokens, but not on behalf of this escrow
+    let result = client.try_release_milestone(&1003, &0, &token_client.address);
+    assert_eq!(result, Err(Ok(Error::BalanceMismatch)));
+
+    assert_eq!(client.get_escrow_balance(&1004), 10000);
+    assert_eq!(token_client.balance(&contract_id), 20000);
+}
+
+#[test]
+fn test_get_release_eligibility() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 604u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 3000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Design"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 3000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Dev"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Deploy"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    client.confirm_delivery(&escrow_id, &0, &depositor);
+    client.cancel_milestones(&escrow_id, &vec![&env, 1u32]);
+
+    let released = client.get_release_eligibility(&escrow_id, &0);
+    assert!(!released.eligible);
+    assert_eq!(released.reason, ReleaseBlocker::AlreadyReleased);
+
+    let cancelled = client.get_release_eligibility(&escrow_id, &1);
+    assert!(!cancelled.eligible);
+    assert_eq!(cancelled.reason, ReleaseBlocker::MilestoneCancelled);
+
+    let pending = client.get_release_eligibility(&escrow_id, &2);
+    assert!(pending.eligible);
+    assert_eq!(pending.reason, ReleaseBlocker::None);
+
+    let result = client.try_get_release_eligibility(&escrow_id, &3);
+    assert_eq!(result, Err(Ok(Error::MilestoneNotFound)));
+}
+
+#[test]
+fn test_release_eligibility_inactive_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 605u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    client.cancel_escrow(&escrow_id);
+
+    let eligibility = client.get_release_eligibility(&escrow_id, &0);
+    assert!(!eligibility.eligible);
+    assert_eq!(eligibility.reason, ReleaseBlocker::EscrowNotActive);
+}
+
+#[test]
+fn test_can_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &None);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 606u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    env.ledger().set_timestamp(1_000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 6000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 2_000,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    assert!(client.can_release(&escrow_id, &0));
+    assert!(client.can_release(&escrow_id, &1));
+
+    client.release_milestone(&escrow_id, &0, &token_client.address);
+    assert!(!client.can_release(&escrow_id, &0));
+
+    // Past milestone 1's own deadline
+    env.ledger().set_timestamp(2_001);
+    assert!(!client.can_release(&escrow_id, &1));
+    assert_eq!(
+        client.get_release_eligibility(&escrow_id, &1).reason,
+        ReleaseBlocker::DeadlinePassed
+    );
+
+    assert_eq!(
+        client.try_can_release(&escrow_id, &2),
+        Err(Ok(Error::MilestoneNotFound))
+    );
+}
+
+#[test]
+fn test_release_rejected_when_contract_underfunded() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, token_client, recipient) = create_three_milestone_escrow(&env, 2803);
+
+    // Simulate the contract's holdings draining outside of escrow bookkeeping
+    token_client.burn(&client.address, &5500);
+    assert!(!client.can_release(&2803, &0));
+
+    let result = client.try_release_milestone(&2803, &0, &token_client.address);
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+    let depositor = client.get_escrow(&2803).depositor;
+    let result = client.try_confirm_delivery(&2803, &0, &depositor);
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+    assert_eq!(token_client.balance(&recipient), 0);
+    assert_eq!(client.get_escrow(&2803).total_released, 0);
+}
+
+// ============================================================================
+// Admin Override Tests
+// ============================================================================
+
+#[test]
+fn test_admin_force_cancel_stuck_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 1100u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 6000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    client.release_milestone(&escrow_id, &0, &token_client.address);
+
+    // Partially released, so the depositor can no longer cancel it
+    assert_eq!(
+        client.try_cancel_escrow(&escrow_id),
+        Err(Ok(Error::MilestoneAlreadyReleased))
+    );
+
+    client.admin_set_status(&escrow_id, &EscrowStatus::Cancelled);
+
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (symbol_short!("admin_st"), escrow_id).into_val(&env),
+                (
+                    EscrowStatus::Active,
+                    EscrowStatus::Cancelled,
+                    treasury.clone()
+                )
+                    .into_val(&env),
+            ),
+        ]
+    );
+
+    // Remaining funds go back to the depositor rather than being stranded
+    assert_eq!(client.get_state(&escrow_id), EscrowStatus::Cancelled);
+    assert_eq!(token_client.balance(&depositor), 6000);
+    assert_eq!(token_client.balance(&recipient), 4000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_admin_cannot_complete_escrow_holding_funds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &None);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &20000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &1101,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    client.create_escrow(
+        &1102,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    let result = client.try_admin_set_status(&1101, &EscrowStatus::Completed);
+    assert_eq!(result, Err(Ok(Error::InvalidStatusTransition)));
+
+    let result = client.try_admin_set_status(&1101, &EscrowStatus::Active);
+    assert_eq!(result, Err(Ok(Error::InvalidStatusTransition)));
+
+    // Batch override applies to every listed escrow
+    client.admin_set_statuses(&vec![&env, 1101u64, 1102u64], &EscrowStatus::Cancelled);
+    assert_eq!(client.get_state(&1101), EscrowStatus::Cancelled);
+    assert_eq!(client.get_state(&1102), EscrowStatus::Cancelled);
+    assert_eq!(token_client.balance(&depositor), 20000);
+}
+
+// ============================================================================
+// Signature Release Tests
+// ============================================================================
+
+// Signs the release message the contract expects for `(contract, escrow_id, index, nonce)`
+fn sign_release(
+    env: &Env,
+    signer: &ed25519_dalek::SigningKey,
+    contract_id: &Address,
+    escrow_id: u64,
+    milestone_index: u32,
+    nonce: u64,
+) -> BytesN<64> {
+    use ed25519_dalek::Signer;
+    use soroban_sdk::xdr::ToXdr;
+
+    let message = (contract_id.clone(), escrow_id, milestone_index, nonce).to_xdr(env);
+    let message: std::vec::Vec<u8> = message.iter().collect();
+    BytesN::from_array(env, &signer.sign(&message).to_bytes())
+}
+
+#[test]
+fn test_release_with_signature() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 1200u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 6000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    // Depositor registers a signing key and signs a release off-chain
+    let signer = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    let public_key = BytesN::from_array(&env, &signer.verifying_key().to_bytes());
+    client.set_release_signer(&escrow_id, &public_key);
+
+    let signature = sign_release(&env, &signer, &contract_id, escrow_id, 0, 0);
+
+    // Recipient submits the signed release
+    env.set_auths(&[]);
+    client.release_with_signature(&escrow_id, &0, &signature);
+
+    assert_eq!(token_client.balance(&recipient), 4000);
+    assert_eq!(client.get_claim_nonce(&escrow_id), 1);
+    assert_eq!(
+        client
+            .get_escrow(&escrow_id)
+            .milestones
+            .get(0)
+            .unwrap()
+            .status,
+        MilestoneStatus::Released
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Crypto, InvalidInput)")]
+fn test_release_with_replayed_signature_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 1201u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 6000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    let signer = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    let public_key = BytesN::from_array(&env, &signer.verifying_key().to_bytes());
+    client.set_release_signer(&escrow_id, &public_key);
+
+    // A signature for milestone 1 at nonce 0 is spent by the first release
+    let signature = sign_release(&env, &signer, &contract_id, escrow_id, 1, 0);
+    client.release_with_signature(&escrow_id, &1, &signature);
+
+    // Replaying it after the nonce moved on fails verification
+    client.release_with_signature(&escrow_id, &1, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Error(Crypto, InvalidInput)")]
+fn test_release_with_signature_for_other_deployment_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let other_deployment = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 1203u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    let signer = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    let public_key = BytesN::from_array(&env, &signer.verifying_key().to_bytes());
+    client.set_release_signer(&escrow_id, &public_key);
+
+    // Same escrow id, index, nonce and key, but signed for another deployment
+    let signature = sign_release(&env, &signer, &other_deployment, escrow_id, 0, 0);
+    client.release_with_signature(&escrow_id, &0, &signature);
+}
+
+#[test]
+fn test_release_with_signature_requires_signer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 1202u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    let result =
+        client.try_release_with_signature(&escrow_id, &0, &BytesN::from_array(&env, &[0; 64]));
+    assert_eq!(result, Err(Ok(Error::SignerNotSet)));
+}
+
+// ============================================================================
+// Migration Tests
+// ============================================================================
+
+#[test]
+fn test_import_partially_released_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 1300u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&treasury, &5000);
+
+    // Legacy escrow with its first milestone paid out and the second partly paid
+    let legacy = Escrow {
+        creator: depositor.clone(),
+        depositor: depositor.clone(),
+        recipient: recipient.clone(),
+        total_amount: 10000,
+        total_released: 5000,
+        milestones: vec![
+            &env,
+            Milestone {
+                amount: 4000,
+                status: MilestoneStatus::Released,
+                description: symbol_short!("Phase1"),
+                deadline: 0,
+                released_so_far: 4000,
+            },
+            Milestone {
+                amount: 6000,
+                status: MilestoneStatus::Pending,
+                description: symbol_short!("Phase2"),
+                deadline: 0,
+                released_so_far: 1000,
+            },
+        ],
+        token: token_client.address.clone(),
+        status: EscrowStatus::Active,
+        payout_token: None,
+        swap_contract: None,
+        updated_at: 0,
+        release_operators: vec![&env],
+        release_blocked: false,
+        arbiter: None,
+        auto_complete: false,
+    };
+
+    // The outstanding funds are migrated alongside the state
+    client.import_escrow(&escrow_id, &legacy);
+    assert_eq!(token_client.balance(&treasury), 0);
+    assert_eq!(token_client.balance(&contract_id), 5000);
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.depositor, depositor);
+    assert_eq!(escrow.total_amount, 10000);
+    assert_eq!(escrow.total_released, 5000);
+    assert_eq!(client.get_escrow_balance(&escrow_id), 5000);
+    assert_eq!(client.get_milestone_released(&escrow_id, &1), 1000);
+    assert_eq!(
+        client.get_escrows_by_depositor(&depositor),
+        vec![&env, escrow_id]
+    );
+    assert_eq!(
+        client.get_escrows_by_recipient(&recipient),
+        vec![&env, escrow_id]
+    );
+
+    // Imported escrow keeps working like a native one
+    client.release_milestone(&escrow_id, &1, &token_client.address);
+    client.complete_escrow(&escrow_id);
+
+    assert_eq!(client.get_state(&escrow_id), EscrowStatus::Completed);
+    assert_eq!(token_client.balance(&recipient), 5000);
+
+    // The id is now taken
+    let result = client.try_import_escrow(&escrow_id, &legacy);
+    assert_eq!(result, Err(Ok(Error::EscrowAlreadyExists)));
+}
+
+#[test]
+fn test_import_inconsistent_escrow_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &None);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+
+    let (token_client, _) = create_token_contract(&env, &admin);
+
+    // Claims more released than its released milestones add up to
+    let legacy = Escrow {
+        creator: depositor.clone(),
+        depositor: depositor.clone(),
+        recipient: recipient.clone(),
+        total_amount: 10000,
+        total_released: 6000,
+        milestones: vec![
+            &env,
+            Milestone {
+                amount: 4000,
+                status: MilestoneStatus::Released,
+                description: symbol_short!("Phase1"),
+                deadline: 0,
+                released_so_far: 4000,
+            },
+            Milestone {
+                amount: 6000,
+                status: MilestoneStatus::Pending,
+                description: symbol_short!("Phase2"),
+                deadline: 0,
+                released_so_far: 0,
+            },
+        ],
+        token: token_client.address.clone(),
+        status: EscrowStatus::Active,
+        payout_token: None,
+        swap_contract: None,
+        updated_at: 0,
+        release_operators: vec![&env],
+        release_blocked: false,
+        arbiter: None,
+        auto_complete: false,
+    };
+
+    let result = client.try_import_escrow(&1301, &legacy);
+    assert_eq!(result, Err(Ok(Error::TotalAmountMismatch)));
+}
+
+// ============================================================================
+// Release Operator Tests
+// ============================================================================
+
+#[test]
+fn test_release_operator_can_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 1400u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 6000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    client.add_release_operator(&escrow_id, &operator);
+    assert_eq!(
+        client.get_escrow(&escrow_id).release_operators,
+        vec![&env, operator.clone()]
+    );
+
+    // A listed operator releases successfully
+    client.operator_release(&escrow_id, &0, &operator);
+    assert_eq!(token_client.balance(&recipient), 4000);
+
+    // A non-listed address is rejected
+    let result = client.try_operator_release(&escrow_id, &1, &outsider);
+    assert_eq!(result, Err(Ok(Error::UnauthorizedAccess)));
+
+    // Removed operators lose access
+    client.remove_release_operator(&escrow_id, &operator);
+    let result = client.try_operator_release(&escrow_id, &1, &operator);
+    assert_eq!(result, Err(Ok(Error::UnauthorizedAccess)));
+}
+
+// ============================================================================
+// Recipient Pause Tests
+// ============================================================================
+
+#[test]
+fn test_recipient_pause_blocks_release_until_resumed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 1500u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 6000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    client.request_pause(&escrow_id);
+    assert!(client.get_escrow(&escrow_id).release_blocked);
+
+    // Releases are blocked while the pause stands
+    let result = client.try_release_milestone(&escrow_id, &0, &token_client.address);
+    assert_eq!(result, Err(Ok(Error::ReleaseBlocked)));
+    let result = client.try_confirm_delivery(&escrow_id, &0, &depositor);
+    assert_eq!(result, Err(Ok(Error::ReleaseBlocked)));
+
+    // Depositor confirms the block: still blocked
+    client.acknowledge_pause(&escrow_id, &false);
+    let result = client.try_release_milestone(&escrow_id, &0, &token_client.address);
+    assert_eq!(result, Err(Ok(Error::ReleaseBlocked)));
+
+    // Depositor resumes: releases work again
+    client.acknowledge_pause(&escrow_id, &true);
+    assert!(!client.get_escrow(&escrow_id).release_blocked);
+    client.release_milestone(&escrow_id, &0, &token_client.address);
+    assert_eq!(token_client.balance(&recipient), 4000);
+}
+
+// ============================================================================
+// Timelocked Release Tests
+// ============================================================================
+
+#[test]
+fn test_proposed_release_respects_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+    client.set_release_delay(&3600);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 1600u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    env.ledger().set_timestamp(1_000);
+    client.propose_release(&escrow_id, &0);
+    assert_eq!(client.get_proposed_release(&escrow_id, &0), Some(4_600));
+
+    // Executing before the delay elapses fails
+    env.ledger().set_timestamp(4_599);
+    let result = client.try_execute_release(&escrow_id, &0);
+    assert_eq!(result, Err(Ok(Error::ProposalNotReady)));
+    assert_eq!(token_client.balance(&recipient), 0);
+
+    // Executing after the delay succeeds
+    env.ledger().set_timestamp(4_600);
+    client.execute_release(&escrow_id, &0);
+    assert_eq!(token_client.balance(&recipient), 10000);
+    assert_eq!(client.get_proposed_release(&escrow_id, &0), None);
+}
+
+#[test]
+fn test_cancel_proposed_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 1601u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    client.propose_release(&escrow_id, &0);
+    let result = client.try_propose_release(&escrow_id, &0);
+    assert_eq!(result, Err(Ok(Error::ProposalExists)));
+
+    client.cancel_proposed_release(&escrow_id, &0);
+
+    // Once cancelled the proposal can no longer execute, even after the delay
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + client.get_release_delay());
+    let result = client.try_execute_release(&escrow_id, &0);
+    assert_eq!(result, Err(Ok(Error::ProposalNotFound)));
+    assert_eq!(token_client.balance(&recipient), 0);
+}
+
+// ============================================================================
+// Sunset Tests
+// ============================================================================
+
+#[test]
+fn test_sunset_blocks_creation_but_not_wind_down() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 1700u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &20000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 10000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    assert!(!client.is_sunset());
+    client.sunset();
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (symbol_short!("sunset"),).into_val(&env),
+                treasury.into_val(&env),
+            ),
+        ]
+    );
+    assert!(client.is_sunset());
+
+    // New escrows are rejected
+    let result = client.try_create_escrow(
+        &(escrow_id + 1),
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    assert_eq!(result, Err(Ok(Error::ContractSunset)));
+
+    // The existing escrow still winds down normally
+    client.release_milestone(&escrow_id, &0, &token_client.address);
+    client.complete_escrow(&escrow_id);
+    assert_eq!(token_client.balance(&recipient), 10000);
+    assert_eq!(
+        client.get_escrow(&escrow_id).status,
+        EscrowStatus::Completed
+    );
+}
+
+#[test]
+fn test_sunset_rejects_non_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.initialize(&treasury, &None);
+
+    // A second initialize cannot install a new admin
+    let result = client.try_initialize(&stranger, &None);
+    assert_eq!(result, Err(Ok(Error::AlreadyInitialized)));
+
+    // The stranger's own authorization is not enough to sunset
+    env.mock_auths(&[MockAuth {
+        address: &stranger,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "sunset",
+            args: ().into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_sunset().is_err());
+    assert!(!client.is_sunset());
+}
+
+// ============================================================================
+// Release Event Tests
+// ============================================================================
+
+#[test]
+fn test_release_events_track_cumulative_total() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(100));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 1900u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 2000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 3000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 5000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase3"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    client.release_milestone(&escrow_id, &0, &token_client.address);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (symbol_short!("fee_coll"), escrow_id, 0u32).into_val(&env),
+                (20i128, treasury.clone()).into_val(&env),
+            ),
+            (
+                contract_id.clone(),
+                (symbol_short!("released"), escrow_id, 0u32).into_val(&env),
+                (2000i128, recipient.clone(), 2000i128).into_val(&env),
+            ),
+        ]
+    );
+
+    client.release_milestone(&escrow_id, &2, &token_client.address);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (symbol_short!("fee_coll"), escrow_id, 2u32).into_val(&env),
+                (50i128, treasury.clone()).into_val(&env),
+            ),
+            (
+                contract_id.clone(),
+                (symbol_short!("released"), escrow_id, 2u32).into_val(&env),
+                (5000i128, recipient.clone(), 7000i128).into_val(&env),
+            ),
+        ]
+    );
+
+    // Event amounts are gross of the fee, while the recipient is paid net
+    assert_eq!(token_client.balance(&recipient), 6930);
+}
+
+// ============================================================================
+// Dispute Tests
+// ============================================================================
+
+#[test]
+fn test_raise_dispute_blocks_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 2000u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 6000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    client.raise_dispute(&escrow_id, &1, &recipient);
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(
+        escrow.milestones.get(1).unwrap().status,
+        MilestoneStatus::Disputed
+    );
+
+    // The disputed milestone cannot be released by any path
+    let result = client.try_release_milestone(&escrow_id, &1, &token_client.address);
+    assert_eq!(result, Err(Ok(Error::MilestoneDisputed)));
+    let result = client.try_confirm_delivery(&escrow_id, &1, &depositor);
+    assert_eq!(result, Err(Ok(Error::MilestoneDisputed)));
+
+    // Other milestones are unaffected
+    client.release_milestone(&escrow_id, &0, &token_client.address);
+    assert_eq!(token_client.balance(&recipient), 4000);
+}
+
+#[test]
+fn test_raise_dispute_rejects_released_milestone_and_outsiders() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 2001u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 6000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    client.release_milestone(&escrow_id, &0, &token_client.address);
+    let result = client.try_raise_dispute(&escrow_id, &0, &depositor);
+    assert_eq!(result, Err(Ok(Error::MilestoneAlreadyReleased)));
+
+    let result = client.try_raise_dispute(&escrow_id, &1, &stranger);
+    assert_eq!(result, Err(Ok(Error::UnauthorizedAccess)));
+
+    client.raise_dispute(&escrow_id, &1, &depositor);
+    let result = client.try_raise_dispute(&escrow_id, &1, &recipient);
+    assert_eq!(result, Err(Ok(Error::MilestoneNotPending)));
+}
+
+#[test]
+fn test_get_disputed_milestones() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _token_client, recipient) = create_three_milestone_escrow(&env, 2002);
+    assert_eq!(client.get_disputed_milestones(&2002), Vec::<u32>::new(&env));
+
+    client.raise_dispute(&2002, &0, &recipient);
+    client.raise_dispute(&2002, &2, &recipient);
+
+    assert_eq!(
+        client.get_disputed_milestones(&2002),
+        vec![&env, 0u32, 2u32]
+    );
+}
+
+fn setup_disputed_escrow<'a>(
+    env: &'a Env,
+    escrow_id: u64,
+) -> (
+    VaultixEscrowClient<'a>,
+    token::Client<'a>,
+    Address,
+    Address,
+    Address,
+) {
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(env, &contract_id);
+
+    let treasury = Address::generate(env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(env);
+    let recipient = Address::generate(env);
+    let arbiter = Address::generate(env);
+    let admin = Address::generate(env);
+
+    let (token_client, token_admin) = create_token_contract(env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        env,
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 6000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    client.set_arbiter(&escrow_id, &arbiter);
+    client.raise_dispute(&escrow_id, &1, &recipient);
+
+    (client, token_client, depositor, recipient, arbiter)
+}
+
+#[test]
+fn test_set_arbiter_only_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _token_client, _recipient) = create_three_milestone_escrow(&env, 2004);
+    let arbiter = Address::generate(&env);
+
+    client.set_arbiter(&2004, &arbiter);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("arbiter"), 2004u64).into_val(&env),
+                arbiter.into_val(&env),
+            ),
+        ]
+    );
+
+    // The appointed arbiter cannot be swapped out afterwards
+    let result = client.try_set_arbiter(&2004, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(Error::ArbiterAlreadySet)));
+    assert_eq!(client.get_escrow(&2004).arbiter, Some(arbiter));
+}
+
+#[test]
+fn test_resolve_dispute_in_favour_of_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, token_client, depositor, recipient, arbiter) = setup_disputed_escrow(&env, 2002);
+
+    client.resolve_dispute(&2002, &1, &true, &arbiter);
+
+    let escrow = client.get_escrow(&2002);
+    assert_eq!(
+        escrow.milestones.get(1).unwrap().status,
+        MilestoneStatus::Released
+    );
+    assert_eq!(escrow.total_released, 6000);
+    assert_eq!(token_client.balance(&recipient), 6000);
+    assert_eq!(token_client.balance(&depositor), 0);
+}
+
+#[test]
+fn test_resolve_dispute_for_recipient_after_deadline() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 2003u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &5000);
+
+    env.ledger().set_timestamp(1_000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 5000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 2_000,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    client.set_arbiter(&escrow_id, &arbiter);
+
+    // Depositor disputes just before the deadline and lets the clock run out
+    env.ledger().set_timestamp(1_999);
+    client.raise_dispute(&escrow_id, &0, &depositor);
+    env.ledger().set_timestamp(3_000);
+
+    client.resolve_dispute(&escrow_id, &0, &true, &arbiter);
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(
+        escrow.milestones.get(0).unwrap().status,
+        MilestoneStatus::Released
+    );
+    assert_eq!(token_client.balance(&recipient), 5000);
+}
+
+#[test]
+fn test_resolve_dispute_in_favour_of_depositor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, token_client, depositor, recipient, arbiter) = setup_disputed_escrow(&env, 2003);
+
+    client.resolve_dispute(&2003, &1, &false, &arbiter);
+
+    let escrow = client.get_escrow(&2003);
+    assert_eq!(
+        escrow.milestones.get(1).unwrap().status,
+        MilestoneStatus::Cancelled
+    );
+    assert_eq!(escrow.total_amount, 4000);
+    assert_eq!(token_client.balance(&depositor), 6000);
+    assert_eq!(token_client.balance(&recipient), 0);
+    assert_eq!(client.get_escrow_balance(&2003), 4000);
+}
+
+#[test]
+fn test_resolve_dispute_rejects_non_arbiter() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _token_client, depositor, _recipient, arbiter) = setup_disputed_escrow(&env, 2004);
+
+    let result = client.try_resolve_dispute(&2004, &1, &false, &depositor);
+    assert_eq!(result, Err(Ok(Error::UnauthorizedAccess)));
+
+    // Only disputed milestones can be resolved
+    let result = client.try_resolve_dispute(&2004, &0, &true, &arbiter);
+    assert_eq!(result, Err(Ok(Error::InvalidStatusTransition)));
+}
+
+#[test]
+fn test_dispute_events_and_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().set_timestamp(1_000);
+    let (client, _token_client, _depositor, recipient, arbiter) = setup_disputed_escrow(&env, 2005);
+    let contract_id = client.address.clone();
+
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (symbol_short!("disputed"), 2005u64, 1u32).into_val(&env),
+                recipient.clone().into_val(&env),
+            ),
+        ]
+    );
+
+    env.ledger().set_timestamp(5_000);
+    client.resolve_dispute(&2005, &1, &true, &arbiter);
+
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (symbol_short!("released"), 2005u64, 1u32).into_val(&env),
+                (6000i128, recipient.clone(), 6000i128).into_val(&env),
+            ),
+            (
+                contract_id.clone(),
+                (symbol_short!("resolved"), 2005u64, 1u32).into_val(&env),
+                true.into_val(&env),
+            ),
+        ]
+    );
+
+    // Pending -> Disputed -> Released
+    assert_eq!(
+        client.get_dispute_history(&2005, &1),
+        vec![
+            &env,
+            (1_000u64, MilestoneStatus::Disputed),
+            (5_000u64, MilestoneStatus::Released)
+        ]
+    );
+    assert_eq!(client.get_dispute_history(&2005, &0).len(), 0);
+}
+
+// ============================================================================
+// Batch Release Tests
+// ============================================================================
+
+fn create_three_milestone_escrow<'a>(
+    env: &'a Env,
+    escrow_id: u64,
+) -> (VaultixEscrowClient<'a>, token::Client<'a>, Address) {
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(env, &contract_id);
+
+    let treasury = Address::generate(env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(env);
+    let recipient = Address::generate(env);
+    let admin = Address::generate(env);
+
+    let (token_client, token_admin) = create_token_contract(env, &admin);
+    token_admin.mint(&depositor, &6000);
+
+    let milestones = vec![
+        env,
+        Milestone {
+            amount: 1000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 2000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 3000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase3"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    (client, token_client, recipient)
+}
+
+#[test]
+fn test_release_milestones_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, token_client, recipient) = create_three_milestone_escrow(&env, 2100);
+
+    client.release_milestones(&2100, &vec![&env, 0u32, 2u32]);
+
+    let escrow = client.get_escrow(&2100);
+    assert_eq!(escrow.total_released, 4000);
+    assert_eq!(
+        client.get_milestone_statuses(&2100),
+        vec![
+            &env,
+            MilestoneStatus::Released,
+            MilestoneStatus::Pending,
+            MilestoneStatus::Released
+        ]
+    );
+    assert_eq!(token_client.balance(&recipient), 4000);
+}
+
+#[test]
+fn test_release_milestones_reverts_on_duplicate_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, token_client, recipient) = create_three_milestone_escrow(&env, 2101);
+
+    let result = client.try_release_milestones(&2101, &vec![&env, 0u32, 1u32, 0u32]);
+    assert_eq!(result, Err(Ok(Error::MilestoneAlreadyReleased)));
+
+    // Nothing from the batch was applied
+    let escrow = client.get_escrow(&2101);
+    assert_eq!(escrow.total_released, 0);
+    assert_eq!(client.get_escrow_balance(&2101), 6000);
+    assert_eq!(token_client.balance(&recipient), 0);
+}
+
+// ============================================================================
+// Pause Tests
+// ============================================================================
+
+#[test]
+fn test_pause_blocks_release_until_unpaused() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, token_client, recipient) = create_three_milestone_escrow(&env, 2200);
+    let escrow = client.get_escrow(&2200);
+
+    client.pause();
+    assert!(client.is_paused());
+
+    let result = client.try_release_milestone(&2200, &0, &token_client.address);
+    assert_eq!(result, Err(Ok(Error::ContractPaused)));
+    let result = client.try_cancel_escrow(&2200);
+    assert_eq!(result, Err(Ok(Error::ContractPaused)));
+
+    // Read-only queries keep working
+    assert_eq!(client.get_remaining_amount(&2200), 6000);
+
+    client.unpause();
+    assert!(!client.is_paused());
+
+    client.release_milestone(&2200, &0, &escrow.token);
+    assert_eq!(token_client.balance(&recipient), 1000);
+}
+
+#[test]
+fn test_pause_gated_on_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    client.initialize(&treasury, &None);
+    assert_eq!(client.get_admin(), treasury);
+
+    // Nobody can take over the admin role by re-initializing
+    let result = client.try_initialize(&attacker, &Some(10000));
+    assert_eq!(result, Err(Ok(Error::AlreadyInitialized)));
+    assert_eq!(client.get_admin(), treasury);
+    assert_eq!(client.get_config(), (treasury.clone(), DEFAULT_FEE_BPS));
+
+    // The admin role is stored apart from the fee treasury
+    client.set_admin(&new_admin);
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.get_config().0, treasury);
+
+    client.pause();
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, new_admin);
+    assert!(client.is_paused());
+}
+
+// ============================================================================
+// Partial Release Tests
+// ============================================================================
+
+#[test]
+fn test_release_partial_until_fully_paid() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, token_client, recipient) = create_three_milestone_escrow(&env, 2300);
+
+    client.release_partial(&2300, &2, &1000);
+    assert_eq!(client.get_milestone_released(&2300, &2), 1000);
+    let milestone = client.get_milestone(&2300, &2);
+    assert_eq!(milestone.status, MilestoneStatus::Pending);
+    assert_eq!(milestone.released_so_far, 1000);
+    assert_eq!(token_client.balance(&recipient), 1000);
+
+    client.release_partial(&2300, &2, &2000);
+    let escrow = client.get_escrow(&2300);
+    assert_eq!(
+        escrow.milestones.get(2).unwrap().status,
+        MilestoneStatus::Released
+    );
+    assert_eq!(escrow.total_released, 3000);
+    assert_eq!(client.get_milestone_released(&2300, &2), 3000);
+    assert_eq!(token_client.balance(&recipient), 3000);
+    assert_eq!(client.get_escrow_balance(&2300), 3000);
+}
+
+#[test]
+fn test_release_partial_rejects_excess_and_pays_remainder_on_full_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, token_client, recipient) = create_three_milestone_escrow(&env, 2301);
+
+    client.release_partial(&2301, &1, &500);
+    let result = client.try_release_partial(&2301, &1, &1501);
+    assert_eq!(result, Err(Ok(Error::PartialAmountExceeded)));
+
+    // A regular release only pays out what is left of the milestone
+    client.release_milestone(&2301, &1, &token_client.address);
+    let escrow = client.get_escrow(&2301);
+    assert_eq!(escrow.total_released, 2000);
+    assert_eq!(token_client.balance(&recipient), 2000);
+    assert_eq!(client.get_escrow_balance(&2301), 4000);
+}
+
+// ============================================================================
+// Auto Id Tests
+// ============================================================================
+
+#[test]
+fn test_create_escrow_auto_assigns_sequential_ids() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &3000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 1000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    for expected_id in 0..3u64 {
+        let escrow_id =
+            client.create_escrow_auto(&depositor, &recipient, &milestones, &token_client.address);
+        assert_eq!(escrow_id, expected_id);
+
+        let escrow = client.get_escrow(&escrow_id);
+        assert_eq!(escrow.depositor, depositor);
+        assert_eq!(escrow.total_amount, 1000);
+    }
+}
+
+#[test]
+fn test_create_escrows_bulk() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let admin = Address::generate(&env);
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &6000);
+
+    let mut escrows = Vec::new(&env);
+    for amount in [1000i128, 2000i128, 3000i128] {
+        escrows.push_back(CreateEscrowArgs {
+            depositor: depositor.clone(),
+            recipient: Address::generate(&env),
+            milestones: vec![
+                &env,
+                Milestone {
+                    amount,
+                    status: MilestoneStatus::Pending,
+                    description: symbol_short!("Work"),
+                    deadline: 0,
+                    released_so_far: 0,
+                },
+            ],
+            token: token_client.address.clone(),
+        });
+    }
+
+    let escrow_ids = client.create_escrows(&escrows);
+    assert_eq!(escrow_ids, vec![&env, 0u64, 1u64, 2u64]);
+    for (escrow_id, args) in escrow_ids.iter().zip(escrows.iter()) {
+        let escrow = client.get_escrow(&escrow_id);
+        assert_eq!(escrow.recipient, args.recipient);
+        assert_eq!(escrow.total_amount, args.milestones.get(0).unwrap().amount);
+    }
+    assert_eq!(token_client.balance(&contract_id), 6000);
+}
+
+#[test]
+fn test_create_escrows_reverts_on_invalid_entry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let admin = Address::generate(&env);
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &3000);
+
+    let mut escrows = Vec::new(&env);
+    for amount in [1000i128, 0i128, 2000i128] {
+        escrows.push_back(CreateEscrowArgs {
+            depositor: depositor.clone(),
+            recipient: Address::generate(&env),
+            milestones: vec![
+                &env,
+                Milestone {
+                    amount,
+                    status: MilestoneStatus::Pending,
+                    description: symbol_short!("Work"),
+                    deadline: 0,
+                    released_so_far: 0,
+                },
+            ],
+            token: token_client.address.clone(),
+        });
+    }
+
+    let result = client.try_create_escrows(&escrows);
+    assert_eq!(result, Err(Ok(Error::ZeroAmount)));
+
+    // The valid first entry was rolled back along with the rest
+    assert!(
+        client
+            .get_escrows(&vec![&env, 0u64])
+            .get(0)
+            .unwrap()
+            .is_none()
+    );
+    assert_eq!(token_client.balance(&depositor), 3000);
+    assert_eq!(client.get_escrows_by_depositor(&depositor).len(), 0);
+}
+
+// ============================================================================
+// Escrow Index Tests
+// ============================================================================
+
+#[test]
+fn test_get_escrows_by_depositor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let other_depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &2000);
+    token_admin.mint(&other_depositor, &1000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 1000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+
+    client.create_escrow(
+        &2400,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    client.create_escrow(
+        &2401,
+        &other_depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    client.create_escrow(
+        &2402,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    assert_eq!(
+        client.get_escrows_by_depositor(&depositor),
+        vec![&env, 2400u64, 2402u64]
+    );
+    assert_eq!(
+        client.get_escrows_by_depositor(&other_depositor),
+        vec![&env, 2401u64]
+    );
+    assert_eq!(
+        client.get_escrows_by_depositor(&recipient),
+        Vec::<u64>::new(&env)
+    );
+}
+
+#[test]
+fn test_get_depositor_total_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    let (other_token, other_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &5000);
+    other_admin.mint(&depositor, &1000);
+
+    let milestones = |first: i128, second: i128| {
+        vec![
+            &env,
+            Milestone {
+                amount: first,
+                status: MilestoneStatus::Pending,
+                description: symbol_short!("Phase1"),
+                deadline: 0,
+                released_so_far: 0,
+            },
+            Milestone {
+                amount: second,
+                status: MilestoneStatus::Pending,
+                description: symbol_short!("Phase2"),
+                deadline: 0,
+                released_so_far: 0,
+            },
+        ]
+    };
+
+    client.create_escrow(
+        &2404,
+        &depositor,
+        &recipient,
+        &milestones(1000, 2000),
+        &token_client.address,
+    );
+    client.create_escrow(
+        &2405,
+        &depositor,
+        &recipient,
+        &milestones(500, 1500),
+        &token_client.address,
+    );
+    client.create_escrow(
+        &2406,
+        &depositor,
+        &recipient,
+        &milestones(400, 600),
+        &other_token.address,
+    );
+
+    assert_eq!(
+        client.get_depositor_total_locked(&depositor, &token_client.address),
+        5000
+    );
+
+    // Released funds no longer count as locked
+    client.release_milestone(&2404, &0, &token_client.address);
+    assert_eq!(
+        client.get_depositor_total_locked(&depositor, &token_client.address),
+        4000
+    );
+    assert_eq!(
+        client.get_depositor_total_locked(&depositor, &other_token.address),
+        1000
+    );
+    assert_eq!(
+        client.get_depositor_total_locked(&recipient, &token_client.address),
+        0
+    );
+}
+
+#[test]
+fn test_get_depositor_total_locked_beyond_index_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &101);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 1,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Work"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    for escrow_id in 2500u64..2601 {
+        client.create_escrow(
+            &escrow_id,
+            &depositor,
+            &recipient,
+            &milestones,
+            &token_client.address,
+        );
+    }
+
+    // The oldest escrow fell out of the 100-entry index but still counts
+    assert_eq!(client.get_escrows_by_depositor(&depositor).len(), 100);
+    assert_eq!(
+        client.get_depositor_total_locked(&depositor, &token_client.address),
+        101
+    );
+
+    client.cancel_escrow(&2500);
+    assert_eq!(
+        client.get_depositor_total_locked(&depositor, &token_client.address),
+        100
+    );
+}
+
+#[test]
+fn test_get_escrows_by_recipient_follows_recipient_change() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _token_client, recipient) = create_three_milestone_escrow(&env, 2403);
+    let new_recipient = Address::generate(&env);
+
+    assert_eq!(
+        client.get_escrows_by_recipient(&recipient),
+        vec![&env, 2403u64]
+    );
+    assert_eq!(
+        client.get_escrows_by_recipient(&new_recipient),
+        Vec::<u64>::new(&env)
+    );
+
+    client.change_recipient(&2403, &new_recipient);
+
+    assert_eq!(
+        client.get_escrows_by_recipient(&recipient),
+        Vec::<u64>::new(&env)
+    );
+    assert_eq!(
+        client.get_escrows_by_recipient(&new_recipient),
+        vec![&env, 2403u64]
+    );
+}
+
+// ============================================================================
+// Milestone Deadline Tests
+// ============================================================================
+
+#[test]
+fn test_milestone_deadline_blocks_late_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 2500u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    env.ledger().set_timestamp(1_000);
+
+    // Only the first phase has its own due date
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 2_000,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 6000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    env.ledger().set_timestamp(2_001);
+
+    let result = client.try_release_milestone(&escrow_id, &0, &token_client.address);
+    assert_eq!(result, Err(Ok(Error::DeadlinePassed)));
+
+    client.release_milestone(&escrow_id, &1, &token_client.address);
+    assert_eq!(token_client.balance(&recipient), 6000);
+}
+
+#[test]
+fn test_milestone_deadline_releasable_until_due() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 2501u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &4000);
+
+    env.ledger().set_timestamp(1_000);
+
+    let mut milestones = vec![
+        &env,
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 1_000,
+            released_so_far: 0,
+        },
+    ];
+
+    // A deadline that has already arrived is rejected at creation
+    let result = client.try_create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidDeadline)));
+
+    let mut milestone = milestones.get(0).unwrap();
+    milestone.deadline = 2_000;
+    milestones.set(0, milestone);
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    env.ledger().set_timestamp(2_000);
+    client.release_milestone(&escrow_id, &0, &token_client.address);
+    assert_eq!(token_client.balance(&recipient), 4000);
+}
+
+// ============================================================================
+// Force Cancel Tests
+// ============================================================================
+
+#[test]
+fn test_force_cancel_refunds_unreleased_remainder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 2600u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 6000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+    client.release_milestone(&escrow_id, &0, &token_client.address);
+
+    // The regular cancel path refuses once anything was released
+    assert_eq!(
+        client.try_cancel_escrow(&escrow_id),
+        Err(Ok(Error::MilestoneAlreadyReleased))
+    );
+
+    client.force_cancel(&escrow_id);
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Cancelled);
+    assert_eq!(
+        client.get_milestone_statuses(&escrow_id),
+        vec![&env, MilestoneStatus::Released, MilestoneStatus::Cancelled]
+    );
+    assert_eq!(token_client.balance(&recipient), 4000);
+    assert_eq!(token_client.balance(&depositor), 6000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_force_cancel_rejects_disputed_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, token_client, depositor, recipient, _arbiter) = setup_disputed_escrow(&env, 2601);
+
+    let result = client.try_force_cancel(&2601);
+    assert_eq!(result, Err(Ok(Error::MilestoneDisputed)));
+    assert_eq!(token_client.balance(&depositor), 0);
+    assert_eq!(token_client.balance(&recipient), 0);
+}
+
+#[test]
+fn test_get_escrows_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &3000);
+
+    for (escrow_id, amount) in [(2410u64, 1000i128), (2412u64, 2000i128)] {
+        let milestones = vec![
+            &env,
+            Milestone {
+                amount,
+                status: MilestoneStatus::Pending,
+                description: symbol_short!("Work"),
+                deadline: 0,
+                released_so_far: 0,
+            },
+        ];
+        client.create_escrow(
+            &escrow_id,
+            &depositor,
+            &recipient,
+            &milestones,
+            &token_client.address,
+        );
+    }
+
+    let escrows = client.get_escrows(&vec![&env, 2412u64, 2411u64, 2410u64]);
+    assert_eq!(escrows.len(), 3);
+    assert_eq!(escrows.get(0).unwrap().unwrap().total_amount, 2000);
+    assert!(escrows.get(1).unwrap().is_none());
+    assert_eq!(escrows.get(2).unwrap().unwrap().total_amount, 1000);
+
+    let mut too_many = Vec::new(&env);
+    for escrow_id in 0..51u64 {
+        too_many.push_back(escrow_id);
+    }
+    assert_eq!(
+        client.try_get_escrows(&too_many),
+        Err(Ok(Error::VectorTooLarge))
+    );
+}
+
+// ============================================================================
+// Metadata Tests
+// ============================================================================
+
+#[test]
+fn test_version_and_metadata() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    assert_eq!(client.version(), 1);
+    assert_eq!(
+        client.metadata(),
+        EscrowMetadata {
+            admin: None,
+            fee_bps: 50,
+            max_milestones: 20,
+            paused: false,
+        }
+    );
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(250));
+    client.set_max_milestones(&8);
+    client.pause();
+
+    assert_eq!(
+        client.metadata(),
+        EscrowMetadata {
+            admin: Some(treasury),
+            fee_bps: 250,
+            max_milestones: 8,
+            paused: true,
+        }
+    );
+}
+
+// ============================================================================
+// Milestone Refund Tests
+// ============================================================================
+
+#[test]
+fn test_refund_milestone() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(0));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let escrow_id = 2700u64;
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10000);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            amount: 4000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase1"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+        Milestone {
+            amount: 6000,
+            status: MilestoneStatus::Pending,
+            description: symbol_short!("Phase2"),
+            deadline: 0,
+            released_so_far: 0,
+        },
+    ];
+    client.create_escrow(
+        &escrow_id,
+        &depositor,
+        &recipient,
+        &milestones,
+        &token_client.address,
+    );
+
+    client.refund_milestone(&escrow_id, &1);
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(
+        escrow.milestones.get(1).unwrap().status,
+        MilestoneStatus::Refunded
+    );
+    assert_eq!(escrow.total_amount, 4000);
+    assert_eq!(token_client.balance(&depositor), 6000);
+
+    // A refunded milestone cannot be paid out or refunded again
+    let result = client.try_release_milestone(&escrow_id, &1, &token_client.address);
+    assert_eq!(result, Err(Ok(Error::MilestoneNotPending)));
+    let result = client.try_refund_milestone(&escrow_id, &1);
+    assert_eq!(result, Err(Ok(Error::MilestoneNotPending)));
+
+    // Milestone 0 is still releasable, after which the escrow can complete
+    client.release_milestone(&escrow_id, &0, &token_client.address);
+    let result = client.try_refund_milestone(&escrow_id, &0);
+    assert_eq!(result, Err(Ok(Error::MilestoneAlreadyReleased)));
+
+    client.complete_escrow(&escrow_id);
+    assert_eq!(client.get_state(&escrow_id), EscrowStatus::Completed);
+    assert_eq!(token_client.balance(&recipient), 4000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+// ============================================================================
+// Recipient Change Tests
+// ============================================================================
+
+#[test]
+fn test_change_recipient_before_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, token_client, recipient) = create_three_milestone_escrow(&env, 2800);
+    let escrow = client.get_escrow(&2800);
+    let new_recipient = Address::generate(&env);
+
+    let result = client.try_change_recipient(&2800, &escrow.depositor);
+    assert_eq!(result, Err(Ok(Error::SelfDealing)));
+
+    client.change_recipient(&2800, &new_recipient);
+    assert_eq!(client.get_escrow(&2800).recipient, new_recipient);
+
+    client.release_milestone(&2800, &0, &token_client.address);
+    assert_eq!(token_client.balance(&new_recipient), 1000);
+    assert_eq!(token_client.balance(&recipient), 0);
+}
+
+#[test]
+fn test_change_recipient_rejected_after_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, token_client, recipient) = create_three_milestone_escrow(&env, 2801);
+
+    client.release_milestone(&2801, &0, &token_client.address);
+
+    let result = client.try_change_recipient(&2801, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(Error::MilestoneAlreadyReleased)));
+    assert_eq!(client.get_escrow(&2801).recipient, recipient);
+}
+
+// ============================================================================
+// Depositor Transfer Tests
+// ============================================================================
+
+#[test]
+fn test_transfer_depositor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, token_client, recipient) = create_three_milestone_escrow(&env, 2802);
+    let old_depositor = client.get_escrow(&2802).depositor;
+    let new_depositor = Address::generate(&env);
+
+    // The old depositor set up a signer, an operator and a pending proposal
+    let old_signer = ed25519_dalek::SigningKey::from_bytes(&[9u8; 32]);
+    let public_key = BytesN::from_array(&env, &old_signer.verifying_key().to_bytes());
+    client.set_release_signer(&2802, &public_key);
+    let operator = Address::generate(&env);
+    client.add_release_operator(&2802, &operator);
+    client.propose_release(&2802, &2);
+
+    let result = client.try_transfer_depositor(&2802, &recipient);
+    assert_eq!(result, Err(Ok(Error::SelfDealing)));
+    let result = client.try_transfer_depositor(&2802, &token_client.address);
+    assert_eq!(result, Err(Ok(Error::InvalidRecipient)));
+    let result = client.try_transfer_depositor(&2802, &client.address);
+    assert_eq!(result, Err(Ok(Error::InvalidRecipient)));
+
+    client.transfer_depositor(&2802, &new_depositor);
+
+    let escrow = client.get_escrow(&2802);
+    assert_eq!(escrow.depositor, new_depositor);
+    assert_eq!(escrow.creator, old_depositor);
+    assert_eq!(client.get_creator(&2802), old_depositor);
+    assert_eq!(
+        client.get_escrows_by_depositor(&new_depositor),
+        vec![&env, 2802u64]
+    );
+    assert_eq!(
+        client.get_escrows_by_depositor(&old_depositor),
+        Vec::<u64>::new(&env)
+    );
+    assert_eq!(
+        client.get_depositor_total_locked(&new_depositor, &token_client.address),
+        6000
+    );
+    assert_eq!(
+        client.get_depositor_total_locked(&old_depositor, &token_client.address),
+        0
+    );
+
+    // Releases now authorize against the new depositor only
+    client.release_milestone(&2802, &0, &token_client.address);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, new_depositor);
+    assert_eq!(token_client.balance(&recipient), 1000);
+
+    // None of the old depositor's release paths survive the transfer
+    let escrow = client.get_escrow(&2802);
+    assert_eq!(escrow.release_operators.len(), 0);
+    let result = client.try_operator_release(&2802, &1, &operator);
+    assert_eq!(result, Err(Ok(Error::UnauthorizedAccess)));
+    assert_eq!(client.get_proposed_release(&2802, &2), None);
+    assert_eq!(client.get_claim_nonce(&2802), 0);
+
+    let signature = sign_release(&env, &old_signer, &client.address, 2802, 1, 0);
+    let result = client.try_release_with_signature(&2802, &1, &signature);
+    assert_eq!(result, Err(Ok(Error::SignerNotSet)));
+}
+
+// ============================================================================
+// Locked Total Tests
+// ============================================================================
+
+#[test]
+fn test_get_total_locked_tracks_fund_movements() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultixEscrow, ());
+    let client = VaultixEscrowClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&treasury, &Some(100));
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+
+    let (token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &5000);
+
+    for (escrow_id, amount) in [(2900u64, 2000i128), (2901u64, 3000i128)] {
+        let milestones = vec![
+            &env,
+            Milestone {
+                amount,
+                status: MilestoneStatus::Pending,
+                description: symbol_short!("Work"),
+                deadline: 0,
+                released_so_far: 0,
+            },
+        ];
+        client.create_escrow(
+            &escrow_id,
+            &depositor,
+            &recipient,
+            &milestones,
+            &token_client.address,
+        );
+    }
+    assert_eq!(client.get_total_locked(&token_client.address), 5000);
+
+    // The full milestone leaves the pool, fee included
+    client.release_milestone(&2900, &0, &token_client.address);
+    assert_eq!(client.get_total_locked(&token_client.address), 3000);
+
+    client.cancel_escrow(&2901);
+    assert_eq!(client.get_total_locked(&token_client.address), 0);
+    assert_eq!(token_client.balance(&contract_id), 0);
 }